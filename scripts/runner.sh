@@ -38,7 +38,7 @@ fi
 case "$language" in
   rust)
     pushd "./solutions/$year/rust" || exit
-    cargo run --bin "day$day"
+    cargo run --quiet --bin aoc -- run --year "$year" --day "$day"
     popd || exit
     ;;
   zig)
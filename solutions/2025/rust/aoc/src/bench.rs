@@ -0,0 +1,553 @@
+use crate::{error::Error, state};
+use aoc_common::{InputProvider, Registry, solution::DynSolution};
+use clap::{Args, builder::RangedU64ValueParser};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// How many standard errors apart two means have to be before a change counts as significant.
+const SIGNIFICANCE: f64 = 3.0;
+
+/// Changes smaller than this fraction of the baseline are too small to be worth flagging.
+const NOISE: f64 = 0.02;
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// Year to benchmark, defaults to the most recent registered year.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Only benchmark a single day instead of every registered day of the year.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Number of untimed runs before the samples are taken.
+    #[arg(long, default_value_t = 3)]
+    warm_up: usize,
+    /// Number of timed runs of every phase.
+    #[arg(long, default_value_t = 20, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    samples: usize,
+    /// Print the results as JSON instead of a table.
+    #[arg(long)]
+    json: bool,
+    /// Save the results as a baseline with this name.
+    #[arg(long, value_name = "NAME", value_parser = state::file_name)]
+    save_baseline: Option<String>,
+    /// Compare the results against the baseline with this name.
+    #[arg(long, value_name = "NAME", value_parser = state::file_name)]
+    compare: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part 1",
+            Self::Part2 => "part 2",
+        })
+    }
+}
+
+/// Summary of the samples of a phase, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        nanos.sort_by(f64::total_cmp);
+
+        let count = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let median = match count % 2 {
+            0 => (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0,
+            _ => nanos[count / 2],
+        };
+        let variance = match count {
+            1 => 0.0,
+            _ => {
+                nanos
+                    .iter()
+                    .map(|sample| (sample - mean).powi(2))
+                    .sum::<f64>()
+                    / (count - 1) as f64
+            }
+        };
+
+        Self {
+            samples: count,
+            min: nanos[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseBench {
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub phases: Vec<PhaseBench>,
+}
+
+/// Results saved under a name to compare later runs against, in `baselines/<name>.toml` in the
+/// state directory.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default, rename = "day")]
+    pub days: Vec<DayBench>,
+}
+
+impl Baseline {
+    pub fn path(state_dir: &Path, name: &str) -> PathBuf {
+        state_dir.join("baselines").join(format!("{name}.toml"))
+    }
+
+    pub fn load(state_dir: &Path, name: &str) -> Result<Self, Error> {
+        let path = Self::path(state_dir, name);
+
+        if !path.exists() {
+            return Err(Error::UnknownBaseline {
+                name: name.to_string(),
+                path,
+            });
+        }
+
+        state::read(&path)
+    }
+
+    /// Saves the results, keeping the results of days that were not benchmarked this time.
+    pub fn save(state_dir: &Path, name: &str, results: &[DayBench]) -> Result<(), Error> {
+        let path = Self::path(state_dir, name);
+        let mut baseline = state::read::<Self>(&path)?;
+
+        baseline.days.retain(|saved| {
+            !results
+                .iter()
+                .any(|result| (result.year, result.day) == (saved.year, saved.day))
+        });
+        baseline.days.extend(results.iter().cloned());
+        baseline.days.sort_by_key(|day| (day.year, day.day));
+
+        state::write(&path, &baseline)
+    }
+
+    fn get(&self, year: u16, day: u8, phase: Phase) -> Option<&Stats> {
+        self.days
+            .iter()
+            .find(|saved| (saved.year, saved.day) == (year, day))?
+            .phases
+            .iter()
+            .find(|saved| saved.phase == phase)
+            .map(|saved| &saved.stats)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Change {
+    Slower,
+    Faster,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    /// Mean of the baseline in nanoseconds.
+    pub baseline: f64,
+    /// Mean of this run in nanoseconds.
+    pub current: f64,
+    /// Relative difference of the means, positive when this run is slower.
+    pub difference: f64,
+    pub change: Change,
+}
+
+impl Change {
+    /// Decides whether the difference between two sets of samples is significant using Welch's
+    /// t-test, ignoring differences that are too small to matter.
+    pub fn between(baseline: &Stats, current: &Stats) -> Self {
+        let difference = current.mean - baseline.mean;
+        let standard_error = (baseline.stddev.powi(2) / baseline.samples as f64
+            + current.stddev.powi(2) / current.samples as f64)
+            .sqrt();
+
+        let significant = match standard_error {
+            0.0 => difference != 0.0,
+            _ => (difference / standard_error).abs() > SIGNIFICANCE,
+        };
+
+        if !significant || (difference / baseline.mean).abs() < NOISE {
+            Self::Unchanged
+        } else if difference > 0.0 {
+            Self::Slower
+        } else {
+            Self::Faster
+        }
+    }
+}
+
+pub fn compare(results: &[DayBench], baseline: &Baseline) -> Vec<Comparison> {
+    results
+        .iter()
+        .flat_map(|result| {
+            result.phases.iter().filter_map(|phase| {
+                let saved = baseline.get(result.year, result.day, phase.phase)?;
+
+                Some(Comparison {
+                    year: result.year,
+                    day: result.day,
+                    phase: phase.phase,
+                    baseline: saved.mean,
+                    current: phase.stats.mean,
+                    difference: (phase.stats.mean - saved.mean) / saved.mean,
+                    change: Change::between(saved, &phase.stats),
+                })
+            })
+        })
+        .collect()
+}
+
+pub fn bench(
+    args: &BenchArgs,
+    registry: &Registry,
+    inputs: &InputProvider,
+    state_dir: &Path,
+) -> Result<bool, Error> {
+    let year = args
+        .year
+        .or(registry.latest_year())
+        .ok_or(Error::NoSolutions)?;
+
+    let solutions = match args.day {
+        Some(day) => vec![
+            registry
+                .get(year, day)
+                .ok_or(Error::UnknownDay { year, day })?,
+        ],
+        None => registry.year(year).collect(),
+    };
+
+    if solutions.is_empty() {
+        return Err(Error::UnknownYear(year));
+    }
+
+    let baseline = args
+        .compare
+        .as_deref()
+        .map(|name| Baseline::load(state_dir, name))
+        .transpose()?;
+
+    let mut success = true;
+    let mut results = Vec::new();
+
+    for solution in solutions {
+        let result = inputs
+            .load(year, solution.day())
+            .map_err(Error::from)
+            .and_then(|input| bench_day(solution, &input, args.warm_up, args.samples));
+
+        match result {
+            Ok(result) => results.push(result),
+            Err(error) => {
+                eprintln!("day {:02}: error: {error}", solution.day());
+                success = false;
+            }
+        }
+    }
+
+    let comparisons = baseline.map(|baseline| compare(&results, &baseline));
+
+    if args.json {
+        let json = match &comparisons {
+            Some(comparisons) => serde_json::to_string_pretty(&serde_json::json!({
+                "results": results,
+                "comparisons": comparisons,
+            })),
+            None => serde_json::to_string_pretty(&results),
+        };
+
+        println!("{}", json.expect("results are serializable to JSON"));
+    } else {
+        print_table(&results);
+
+        if let Some(comparisons) = &comparisons {
+            println!();
+            print_comparisons(comparisons);
+        }
+    }
+
+    if let Some(name) = &args.save_baseline {
+        Baseline::save(state_dir, name, &results)?;
+        eprintln!(
+            "saved baseline '{name}' to '{}'",
+            Baseline::path(state_dir, name).display()
+        );
+    }
+
+    let slower = comparisons
+        .iter()
+        .flatten()
+        .any(|comparison| comparison.change == Change::Slower);
+
+    Ok(success && !slower)
+}
+
+/// Times every phase of a day separately, parsing the input again for every sample.
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    warm_up: usize,
+    samples: usize,
+) -> Result<DayBench, Error> {
+    let mut phases = Vec::new();
+    let mut timings = Vec::<Vec<Duration>>::new();
+
+    for run in 0..warm_up + samples {
+        let sample = run_once(solution, input)?;
+
+        if run >= warm_up {
+            for (index, (phase, duration)) in sample.into_iter().enumerate() {
+                if index == timings.len() {
+                    phases.push(phase);
+                    timings.push(Vec::with_capacity(samples));
+                }
+
+                timings[index].push(duration);
+            }
+        }
+    }
+
+    Ok(DayBench {
+        year: solution.year(),
+        day: solution.day(),
+        phases: phases
+            .iter()
+            .zip(timings)
+            .map(|(&phase, timings)| PhaseBench {
+                phase,
+                stats: Stats::from_samples(&timings),
+            })
+            .collect(),
+    })
+}
+
+/// Times the phases of a single run, leaving out the second part of days without one.
+fn run_once(solution: &dyn DynSolution, input: &str) -> Result<Vec<(Phase, Duration)>, Error> {
+    let start = Instant::now();
+    let parsed = black_box(solution.parse(black_box(input)).map_err(Error::Solution)?);
+    let mut durations = vec![(Phase::Parse, start.elapsed())];
+
+    for phase in [Phase::Part1, Phase::Part2] {
+        let start = Instant::now();
+        let answer = match phase {
+            Phase::Part1 => solution.part1(parsed.as_ref()).map(Some),
+            _ => solution.part2(parsed.as_ref()),
+        };
+
+        let elapsed = start.elapsed();
+
+        if black_box(answer.map_err(Error::Solution)?).is_some() {
+            durations.push((phase, elapsed));
+        }
+    }
+
+    Ok(durations)
+}
+
+fn print_table(results: &[DayBench]) {
+    println!(
+        "{:<4} {:<7} {:>10} {:>10} {:>10} {:>10}",
+        "day", "phase", "min", "median", "mean", "stddev"
+    );
+
+    for result in results {
+        for PhaseBench { phase, stats } in &result.phases {
+            println!(
+                "{:<4} {:<7} {:>10} {:>10} {:>10} {:>10}",
+                format!("{:02}", result.day),
+                phase.to_string(),
+                format_nanos(stats.min),
+                format_nanos(stats.median),
+                format_nanos(stats.mean),
+                format_nanos(stats.stddev),
+            );
+        }
+    }
+}
+
+fn print_comparisons(comparisons: &[Comparison]) {
+    println!(
+        "{:<4} {:<7} {:>10} {:>10} {:>9}",
+        "day", "phase", "baseline", "current", "change"
+    );
+
+    for comparison in comparisons {
+        let change = match comparison.change {
+            Change::Slower => "slower",
+            Change::Faster => "faster",
+            Change::Unchanged => "no change",
+        };
+
+        println!(
+            "{:<4} {:<7} {:>10} {:>10} {:>+8.1}% {change}",
+            format!("{:02}", comparison.day),
+            comparison.phase.to_string(),
+            format_nanos(comparison.baseline),
+            format_nanos(comparison.current),
+            comparison.difference * 100.0,
+        );
+    }
+}
+
+pub fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        ..1e3 => format!("{nanos:.0}ns"),
+        ..1e6 => format!("{:.2}µs", nanos / 1e3),
+        ..1e9 => format!("{:.2}ms", nanos / 1e6),
+        _ => format!("{:.2}s", nanos / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_summarises_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - (5.0f64 / 3.0).sqrt()).abs() < 1e-9);
+        assert_eq!(Stats::from_samples(&samples[..1]).stddev, 0.0);
+    }
+
+    #[test]
+    fn it_times_every_phase() {
+        let registry = aoc2025::registry();
+        let solution = registry.get(2025, 4).unwrap();
+        let input = include_str!("../../day04/data/example-input.txt");
+
+        let result = bench_day(solution, input, 1, 3).unwrap();
+        let phases = result
+            .phases
+            .iter()
+            .map(|phase| (phase.phase, phase.stats.samples))
+            .collect::<Vec<_>>();
+
+        assert_eq!((result.year, result.day), (2025, 4));
+        assert_eq!(
+            phases,
+            [(Phase::Parse, 3), (Phase::Part1, 3), (Phase::Part2, 3)]
+        );
+        assert!(bench_day(solution, "@x", 0, 1).is_err());
+    }
+
+    fn stats(mean: f64, stddev: f64) -> Stats {
+        Stats {
+            samples: 20,
+            min: mean - stddev,
+            median: mean,
+            mean,
+            stddev,
+        }
+    }
+
+    #[test]
+    fn it_flags_significant_changes() {
+        let baseline = stats(1000.0, 10.0);
+
+        assert_eq!(
+            Change::between(&baseline, &stats(1100.0, 10.0)),
+            Change::Slower
+        );
+        assert_eq!(
+            Change::between(&baseline, &stats(500.0, 10.0)),
+            Change::Faster
+        );
+        assert_eq!(
+            Change::between(&baseline, &stats(1005.0, 10.0)),
+            Change::Unchanged
+        );
+        assert_eq!(
+            Change::between(&baseline, &stats(1100.0, 500.0)),
+            Change::Unchanged
+        );
+        assert_eq!(
+            Change::between(&baseline, &stats(1010.0, 0.0)),
+            Change::Unchanged
+        );
+    }
+
+    #[test]
+    fn it_saves_and_compares_against_baselines() {
+        let state_dir = tempfile::tempdir().unwrap();
+        let day = |day, mean| DayBench {
+            year: 2025,
+            day,
+            phases: vec![PhaseBench {
+                phase: Phase::Part1,
+                stats: stats(mean, 1.0),
+            }],
+        };
+
+        assert!(matches!(
+            Baseline::load(state_dir.path(), "main"),
+            Err(Error::UnknownBaseline { .. })
+        ));
+
+        Baseline::save(state_dir.path(), "main", &[day(4, 1000.0), day(5, 10.0)]).unwrap();
+        Baseline::save(state_dir.path(), "main", &[day(4, 2000.0)]).unwrap();
+
+        let baseline = Baseline::load(state_dir.path(), "main").unwrap();
+        assert_eq!(baseline.days, [day(4, 2000.0), day(5, 10.0)]);
+
+        let comparisons = compare(&[day(4, 1000.0), day(6, 1.0)], &baseline);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change, Change::Faster);
+        assert_eq!(comparisons[0].difference, -0.5);
+    }
+
+    #[test]
+    fn it_formats_durations() {
+        assert_eq!(format_nanos(512.0), "512ns");
+        assert_eq!(format_nanos(1_500.0), "1.50µs");
+        assert_eq!(format_nanos(2_250_000.0), "2.25ms");
+        assert_eq!(format_nanos(3e9), "3.00s");
+    }
+}
@@ -0,0 +1,79 @@
+use crate::error::Error;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight EST, which is five hours after midnight UTC.
+const UNLOCK_OFFSET: Duration = Duration::from_secs(5 * 60 * 60);
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+pub fn unlock_time(year: u16, day: u8) -> Result<SystemTime, Error> {
+    let days = days_since_epoch(i64::from(year), 12, i64::from(day));
+    let days = u64::try_from(days).map_err(|_| Error::BeforeEpoch { year, day })?;
+
+    Ok(UNIX_EPOCH + Duration::from_secs(days * SECONDS_PER_DAY) + UNLOCK_OFFSET)
+}
+
+pub fn ensure_unlocked(year: u16, day: u8, now: SystemTime) -> Result<(), Error> {
+    match unlock_time(year, day)?.duration_since(now) {
+        Ok(remaining) if !remaining.is_zero() => Err(Error::Locked {
+            year,
+            day,
+            remaining: format_duration(remaining),
+        }),
+        _ => Ok(()),
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (days, hours, minutes) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60);
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", seconds % 60),
+        (0, 0, _) => format!("{minutes}m {}s", seconds % 60),
+        (0, _, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h"),
+    }
+}
+
+/// Days between the unix epoch and the given date in the proleptic Gregorian calendar.
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_unlocks_at_midnight_est() {
+        let unlock = unlock_time(2025, 4)
+            .unwrap()
+            .duration_since(UNIX_EPOCH)
+            .unwrap();
+
+        // 2025-12-04T05:00:00Z
+        assert_eq!(unlock.as_secs(), 1764824400);
+        assert!(matches!(
+            unlock_time(1969, 4),
+            Err(Error::BeforeEpoch { year: 1969, day: 4 })
+        ));
+    }
+
+    #[test]
+    fn it_refuses_locked_days() {
+        let unlock = unlock_time(2025, 6).unwrap();
+
+        assert!(ensure_unlocked(2025, 6, unlock).is_ok());
+        assert!(ensure_unlocked(2025, 6, unlock + Duration::from_secs(1)).is_ok());
+        assert!(matches!(
+            ensure_unlocked(2025, 6, unlock - Duration::from_secs(90)),
+            Err(Error::Locked { remaining, .. }) if remaining == "1m 30s"
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::error::Error;
+use aoc_common::Config;
+use std::{env, fs};
+use ureq::Agent;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/toodeluna/aoc";
+
+/// Talks to the Advent Of Code website, or to whatever server the base URL points at.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// Creates a client from the config, taking the session token from the `AOC_SESSION`
+    /// environment variable or else from the configured session file.
+    pub fn from_config(config: &Config) -> Result<Self, Error> {
+        let session = match env::var(SESSION_ENV) {
+            Ok(session) => Some(session),
+            Err(_) => match &config.session_file {
+                Some(path) => {
+                    Some(
+                        fs::read_to_string(path).map_err(|source| Error::ReadSession {
+                            path: path.clone(),
+                            source,
+                        })?,
+                    )
+                }
+                None => None,
+            },
+        };
+
+        let session = session
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+
+        Ok(Self::new(base_url, session))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        let url = self.url(path);
+
+        self.agent
+            .get(&url)
+            .header("Cookie", self.cookie()?)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|source| Error::Request {
+                url,
+                source: Box::new(source),
+            })
+    }
+
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, Error> {
+        let url = self.url(path);
+
+        self.agent
+            .post(&url)
+            .header("Cookie", self.cookie()?)
+            .send_form(fields.iter().copied())
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|source| Error::Request {
+                url,
+                source: Box::new(source),
+            })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> Result<String, Error> {
+        self.session
+            .as_ref()
+            .map(|session| format!("session={session}"))
+            .ok_or(Error::MissingSession)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{error::Error, state};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CooldownState {
+    /// Unix timestamp in seconds until which no answers may be submitted.
+    until: Option<u64>,
+}
+
+/// The wait the website imposes between submissions, kept on disk so it holds across runs.
+pub struct Cooldown {
+    path: PathBuf,
+}
+
+impl Cooldown {
+    pub fn new(state_dir: &Path) -> Self {
+        Self {
+            path: state_dir.join("cooldown.toml"),
+        }
+    }
+
+    pub fn remaining(&self, now: SystemTime) -> Result<Option<Duration>, Error> {
+        let state = state::read::<CooldownState>(&self.path)?;
+
+        Ok(state
+            .until
+            .map(|until| UNIX_EPOCH + Duration::from_secs(until))
+            .and_then(|until| until.duration_since(now).ok())
+            .filter(|remaining| !remaining.is_zero()))
+    }
+
+    pub fn start(&self, now: SystemTime, wait: Duration) -> Result<(), Error> {
+        let until = (now + wait)
+            .duration_since(UNIX_EPOCH)
+            .expect("cooldown ends after the unix epoch");
+
+        // Round up so the cooldown never ends before the website's does.
+        let until = until.as_secs() + u64::from(until.subsec_nanos() > 0);
+
+        state::write(&self.path, &CooldownState { until: Some(until) })
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{calendar, client::Client, error::Error};
+use aoc_common::{InputProvider, Registry};
+use clap::Args;
+use std::{fs, path::PathBuf, time::SystemTime};
+
+#[derive(Debug, Args)]
+pub struct DownloadArgs {
+    /// Year to download, defaults to the most recent registered year.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Day to download the input of.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Download {
+    Cached(PathBuf),
+    Fetched(PathBuf),
+}
+
+pub fn download(
+    args: &DownloadArgs,
+    registry: &Registry,
+    client: &Client,
+    inputs: &InputProvider,
+) -> Result<bool, Error> {
+    let year = args
+        .year
+        .or(registry.latest_year())
+        .ok_or(Error::NoSolutions)?;
+
+    match download_input(client, inputs, year, args.day, SystemTime::now())? {
+        Download::Cached(path) => println!(
+            "day {:02}: already cached at '{}'",
+            args.day,
+            path.display()
+        ),
+        Download::Fetched(path) => println!("day {:02}: saved to '{}'", args.day, path.display()),
+    }
+
+    Ok(true)
+}
+
+/// Fetches the input of a day into the inputs directory, unless it is already there.
+pub fn download_input(
+    client: &Client,
+    inputs: &InputProvider,
+    year: u16,
+    day: u8,
+    now: SystemTime,
+) -> Result<Download, Error> {
+    let path = inputs.path(year, day);
+
+    if path.exists() || inputs.encrypted_path(year, day).exists() {
+        return Ok(Download::Cached(path));
+    }
+
+    fetch(
+        client,
+        &format!("/{year}/day/{day}/input"),
+        path,
+        (year, day),
+        now,
+    )
+}
+
+/// Fetches a page of a day that has unlocked into the given path of a cache.
+pub fn fetch(
+    client: &Client,
+    url_path: &str,
+    path: PathBuf,
+    (year, day): (u16, u8),
+    now: SystemTime,
+) -> Result<Download, Error> {
+    calendar::ensure_unlocked(year, day, now)?;
+
+    let contents = client.get(url_path)?;
+    let write_error = |source| Error::WriteDownload {
+        path: path.clone(),
+        source,
+    };
+
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(write_error)?;
+    }
+
+    fs::write(&path, contents).map_err(write_error)?;
+
+    Ok(Download::Fetched(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+    use std::time::Duration;
+
+    fn after_unlock(year: u16, day: u8) -> SystemTime {
+        calendar::unlock_time(year, day).unwrap() + Duration::from_secs(60)
+    }
+
+    #[test]
+    fn it_downloads_inputs_into_the_cache() {
+        let server = StubServer::start(vec![(200, "1-2,3-4\n".to_string())]);
+        let directory = tempfile::tempdir().unwrap();
+        let inputs = InputProvider::new(directory.path());
+        let client = Client::new(server.url(), Some("cookie".to_string()));
+
+        let download = download_input(&client, &inputs, 2025, 2, after_unlock(2025, 2)).unwrap();
+        let requests = server.requests();
+
+        assert_eq!(download, Download::Fetched(inputs.path(2025, 2)));
+        assert_eq!(inputs.load(2025, 2).unwrap(), "1-2,3-4\n");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2025/day/2/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=cookie"));
+    }
+
+    #[test]
+    fn it_never_downloads_cached_inputs_again() {
+        let server = StubServer::start(vec![]);
+        let directory = tempfile::tempdir().unwrap();
+        let inputs = InputProvider::new(directory.path());
+        let client = Client::new(server.url(), None);
+
+        fs::create_dir_all(directory.path().join("2025")).unwrap();
+        fs::write(inputs.path(2025, 3), "cached").unwrap();
+
+        let download = download_input(&client, &inputs, 2025, 3, after_unlock(2025, 3)).unwrap();
+
+        assert_eq!(download, Download::Cached(inputs.path(2025, 3)));
+        assert_eq!(inputs.load(2025, 3).unwrap(), "cached");
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn it_refuses_to_download_locked_days() {
+        let server = StubServer::start(vec![(200, "too early".to_string())]);
+        let directory = tempfile::tempdir().unwrap();
+        let inputs = InputProvider::new(directory.path());
+        let client = Client::new(server.url(), Some("cookie".to_string()));
+        let before_unlock = calendar::unlock_time(2025, 7).unwrap() - Duration::from_secs(60);
+
+        let result = download_input(&client, &inputs, 2025, 7, before_unlock);
+
+        assert!(matches!(result, Err(Error::Locked { day: 7, .. })));
+        assert!(!inputs.path(2025, 7).exists());
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn it_requires_a_session_token() {
+        let server = StubServer::start(vec![]);
+        let directory = tempfile::tempdir().unwrap();
+        let inputs = InputProvider::new(directory.path());
+        let client = Client::new(server.url(), None);
+
+        let result = download_input(&client, &inputs, 2025, 1, after_unlock(2025, 1));
+
+        assert!(matches!(result, Err(Error::MissingSession)));
+    }
+
+    #[test]
+    fn it_reports_failed_requests() {
+        let server = StubServer::start(vec![(400, "bad session".to_string())]);
+        let directory = tempfile::tempdir().unwrap();
+        let inputs = InputProvider::new(directory.path());
+        let client = Client::new(server.url(), Some("expired".to_string()));
+
+        let result = download_input(&client, &inputs, 2025, 1, after_unlock(2025, 1));
+
+        assert!(matches!(result, Err(Error::Request { .. })));
+        assert!(!inputs.path(2025, 1).exists());
+    }
+}
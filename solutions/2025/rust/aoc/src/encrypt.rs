@@ -0,0 +1,38 @@
+use crate::error::Error;
+use aoc_common::{InputProvider, encryption::InputKey};
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct EncryptArgs {
+    /// Keep the plain inputs next to the encrypted ones.
+    #[arg(short, long)]
+    keep: bool,
+}
+
+/// Encrypts the inputs directory, generating a key first when there is none yet.
+pub fn encrypt(args: &EncryptArgs, inputs: &InputProvider) -> Result<bool, Error> {
+    let key_file = inputs.key_file().ok_or(Error::NoKeyFile)?;
+
+    let key = if key_file.exists() {
+        InputKey::from_file(key_file)?
+    } else {
+        let key = InputKey::generate();
+        key.save(key_file)?;
+        println!("generated a new key at '{}'", key_file.display());
+        key
+    };
+
+    for path in inputs.encrypt_all(&key, args.keep)? {
+        println!("encrypted '{}'", path.display());
+    }
+
+    Ok(true)
+}
+
+pub fn decrypt(inputs: &InputProvider) -> Result<bool, Error> {
+    for path in inputs.decrypt_all()? {
+        println!("decrypted '{}'", path.display());
+    }
+
+    Ok(true)
+}
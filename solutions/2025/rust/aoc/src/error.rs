@@ -0,0 +1,103 @@
+use crate::{guesses::Bounds, submit::Verdict};
+use aoc_common::{
+    error::{
+        AnswersError, ConfigError, EncryptionError, ExamplesError, InputError, WorkspaceError,
+    },
+    solution::BoxedError,
+};
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("no solution registered for day {day} of {year}")]
+    UnknownDay { year: u16, day: u8 },
+    #[error("no solutions registered")]
+    NoSolutions,
+    #[error("no solutions registered for {0}")]
+    UnknownYear(u16),
+    #[error("day {day} of {year} has no second part")]
+    MissingPart2 { year: u16, day: u8 },
+    #[error("day {day} of {year} unlocked before the unix epoch, which no puzzle did")]
+    BeforeEpoch { year: u16, day: u8 },
+    #[error("day {day} of {year} is still locked, it unlocks in {remaining}")]
+    Locked {
+        year: u16,
+        day: u8,
+        remaining: String,
+    },
+    #[error("no session token found, set AOC_SESSION or session-file in the config")]
+    MissingSession,
+    #[error("failed to read session token from '{path}': {source}")]
+    ReadSession { path: PathBuf, source: io::Error },
+    #[error("request to '{url}' failed: {source}")]
+    Request {
+        url: String,
+        source: Box<ureq::Error>,
+    },
+    #[error("an answer was submitted too recently, wait {remaining} before submitting again")]
+    CoolingDown { remaining: String },
+    #[error("{answer} was already submitted and rejected: {verdict}")]
+    KnownWrongAnswer { answer: String, verdict: Verdict },
+    #[error("{answer} cannot be right, previous guesses show the answer is {bounds}")]
+    OutOfBounds { answer: String, bounds: Bounds },
+    #[error("unexpected response to submitted answer: '{0}'")]
+    UnexpectedResponse(String),
+    #[error("failed to read state from '{path}': {source}")]
+    ReadState { path: PathBuf, source: io::Error },
+    #[error("failed to parse state in '{path}': {source}")]
+    InvalidState {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("failed to write state to '{path}': {source}")]
+    WriteState { path: PathBuf, source: io::Error },
+    #[error("failed to write download to '{path}': {source}")]
+    WriteDownload { path: PathBuf, source: io::Error },
+    #[error("no key file is configured to encrypt the inputs with")]
+    NoKeyFile,
+    #[error("answers are hashed with the key at '{0}', but there is none, run `aoc encrypt` first")]
+    MissingAnswerKey(PathBuf),
+    #[error(transparent)]
+    Input(#[from] InputError),
+    #[error(transparent)]
+    Encryption(#[from] EncryptionError),
+    #[error("'{0}' cannot be used as a name, it has to be a file name without path separators")]
+    InvalidName(String),
+    #[error("day {day} has no example named '{name}'")]
+    UnknownExample { day: u8, name: String },
+    #[error("failed to read page '{path}': {source}")]
+    ReadPage { path: PathBuf, source: io::Error },
+    #[error("failed to write the puzzle description to '{path}': {source}")]
+    WriteDescription { path: PathBuf, source: io::Error },
+    #[error("the description of day {day} of {year} is not archived at '{path}'")]
+    PuzzleNotArchived { year: u16, day: u8, path: PathBuf },
+    #[error("day {day} already exists at '{path}'")]
+    DayExists { day: u8, path: PathBuf },
+    #[error("failed to read '{path}': {source}")]
+    ReadManifest { path: PathBuf, source: io::Error },
+    #[error("'{0}' does not list the existing days the way the scaffold expects")]
+    UnexpectedManifest(PathBuf),
+    #[error("failed to write '{path}': {source}")]
+    WriteScaffold { path: PathBuf, source: io::Error },
+    #[error("there is no baseline named '{name}' at '{path}'")]
+    UnknownBaseline { name: String, path: PathBuf },
+    #[error("there is no code block {0} on the page")]
+    UnknownBlock(usize),
+    #[error("failed to write trace to '{path}': {source}")]
+    WriteTrace { path: PathBuf, source: io::Error },
+    #[error("day {day} has no examples")]
+    NoExamples { day: u8 },
+    #[error(transparent)]
+    Examples(#[from] ExamplesError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error(transparent)]
+    Workspace(#[from] WorkspaceError),
+    #[error(transparent)]
+    Answers(#[from] AnswersError),
+    #[error(transparent)]
+    Solution(BoxedError),
+    #[error("the solution panicked")]
+    Panicked,
+}
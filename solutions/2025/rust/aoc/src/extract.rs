@@ -0,0 +1,307 @@
+use crate::{client::Client, error::Error, html, state};
+use aoc_common::{Answer, Config, Example, Examples, Registry};
+use clap::Args;
+use std::{fs, path::PathBuf};
+
+#[derive(Debug, Args)]
+pub struct ExtractArgs {
+    /// Year of the puzzle, defaults to the most recent registered year.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Day of the puzzle.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Read the puzzle description from a saved page instead of fetching it.
+    #[arg(long)]
+    page: Option<PathBuf>,
+    /// Numbers of the code blocks to save as examples, as listed when leaving this out.
+    #[arg(long, value_delimiter = ',')]
+    pick: Vec<usize>,
+    /// Names of the picked examples, defaulting to 'example', 'example-2' and so on.
+    #[arg(long, value_delimiter = ',', value_parser = state::file_name)]
+    name: Vec<String>,
+}
+
+/// A `<pre><code>` block of a puzzle description, with the emphasised answers that follow it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub code: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+pub fn extract(args: &ExtractArgs, registry: &Registry, config: &Config) -> Result<bool, Error> {
+    let year = args
+        .year
+        .or(registry.latest_year())
+        .ok_or(Error::NoSolutions)?;
+
+    let page = match &args.page {
+        Some(path) => fs::read_to_string(path).map_err(|source| Error::ReadPage {
+            path: path.clone(),
+            source,
+        })?,
+        None => Client::from_config(config)?.get(&format!("/{year}/day/{}", args.day))?,
+    };
+
+    let blocks = extract_blocks(&page);
+
+    if args.pick.is_empty() {
+        print_blocks(&blocks);
+        return Ok(true);
+    }
+
+    let picked = args
+        .pick
+        .iter()
+        .map(|&number| {
+            number
+                .checked_sub(1)
+                .and_then(|index| blocks.get(index))
+                .ok_or(Error::UnknownBlock(number))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let day_dir = config.workspace.day_dir(args.day);
+
+    if !day_dir.exists() {
+        return Err(Error::UnknownDay {
+            year,
+            day: args.day,
+        });
+    }
+
+    let mut examples = Examples::load(&day_dir.join("data"))?;
+
+    for (index, block) in picked.into_iter().enumerate() {
+        let name = args
+            .name
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| match index {
+                0 => "example".to_string(),
+                _ => format!("example-{}", index + 1),
+            });
+
+        let example = save_example(&mut examples, name, block)?;
+        println!(
+            "day {:02}: saved example '{}' to '{}'",
+            args.day,
+            example.name,
+            example.file.display()
+        );
+    }
+
+    examples.save()?;
+
+    Ok(true)
+}
+
+/// Writes a block as the input of an example and adds the example to the manifest.
+pub fn save_example(
+    examples: &mut Examples,
+    name: String,
+    block: &Block,
+) -> Result<Example, Error> {
+    let file = match name.as_str() {
+        "example" => PathBuf::from("example-input.txt"),
+        name => PathBuf::from(format!("{name}.txt")),
+    };
+
+    let example = Example {
+        name,
+        file,
+        part1: block.part1.clone(),
+        part2: block.part2.clone(),
+    };
+
+    examples.write_input(&example, &block.code)?;
+    examples.insert(example.clone());
+
+    Ok(example)
+}
+
+/// Finds the code blocks in the articles of a puzzle description. The last emphasised code in
+/// the prose of an article is taken as the answer of its part and assigned to the first block of
+/// the article, which is where puzzles give the example input. When the second part has no blocks
+/// of its own, its answer is assigned to the first block of the first part.
+pub fn extract_blocks(page: &str) -> Vec<Block> {
+    let mut blocks = Vec::<Block>::new();
+
+    for (index, article) in html::articles(page).into_iter().take(2).enumerate() {
+        let part = index + 1;
+        let first = blocks.len();
+        let mut prose = String::new();
+        let mut rest = article;
+
+        while let Some(start) = rest.find("<pre><code>") {
+            let code_start = start + "<pre><code>".len();
+            let code_end = rest[code_start..]
+                .find("</code></pre>")
+                .map_or(rest.len(), |end| code_start + end);
+
+            prose.push_str(&rest[..start]);
+            blocks.push(Block {
+                code: html::text(&rest[code_start..code_end]),
+                part1: None,
+                part2: None,
+            });
+
+            rest = &rest[code_end..];
+        }
+
+        prose.push_str(rest);
+
+        let answer = last_emphasised_code(&prose);
+        let target = match part {
+            2 if first == blocks.len() => blocks.first_mut().filter(|first| first.part2.is_none()),
+            _ => blocks.get_mut(first),
+        };
+
+        if let Some(block) = target {
+            match part {
+                1 => block.part1 = answer,
+                _ => block.part2 = answer,
+            }
+        }
+    }
+
+    blocks
+}
+
+fn last_emphasised_code(html: &str) -> Option<Answer> {
+    ["<code><em>", "<em><code>"]
+        .into_iter()
+        .filter_map(|open| {
+            let close = if open == "<code><em>" {
+                "</em></code>"
+            } else {
+                "</code></em>"
+            };
+
+            let start = html.rfind(open)?;
+            let inner = &html[start + open.len()..];
+            let end = inner.find(close)?;
+
+            Some((start, html::text(&inner[..end])))
+        })
+        .max_by_key(|&(start, _)| start)
+        .map(|(_, answer)| answer.parse().unwrap_or_else(|error| match error {}))
+}
+
+fn print_blocks(blocks: &[Block]) {
+    for (index, block) in blocks.iter().enumerate() {
+        let answers = [("part 1", &block.part1), ("part 2", &block.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| Some(format!("{part}: {}", answer.as_ref()?)))
+            .collect::<Vec<_>>();
+
+        let answers = if answers.is_empty() {
+            "no answers".to_string()
+        } else {
+            answers.join(", ")
+        };
+
+        println!("block {} ({answers})", index + 1);
+
+        let lines = block.code.lines().collect::<Vec<_>>();
+
+        for line in lines.iter().take(5) {
+            println!("    {line}");
+        }
+
+        if lines.len() > 5 {
+            println!("    ... {} more lines", lines.len() - 5);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+    use aoc_common::Workspace;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 4: Printing Department ---</h2>
+<p>For example:</p>
+<pre><code>..@@.
+@@@.@
+</code></pre>
+<p>Rolls such as <code>@</code> can be reached, like this:</p>
+<pre><code>..x<em>@</em>.
+</code></pre>
+<p>In this example, there are <code><em>13</em></code> rolls.</p>
+</article>
+<p>Your puzzle answer was <code>5150</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Stop once no more rolls &amp; tiles can be removed, after removing <code><em>43</em></code> rolls.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn it_extracts_blocks_and_their_answers() {
+        assert_eq!(
+            extract_blocks(PAGE),
+            [
+                Block {
+                    code: "..@@.\n@@@.@\n".to_string(),
+                    part1: Some(Answer::Number(13)),
+                    part2: Some(Answer::Number(43)),
+                },
+                Block {
+                    code: "..x@.\n".to_string(),
+                    part1: None,
+                    part2: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_fetches_the_page_and_saves_picked_examples() {
+        let server = StubServer::start(vec![(200, PAGE.to_string())]);
+        let client = Client::new(server.url(), Some("cookie".to_string()));
+        let directory = tempfile::tempdir().unwrap();
+
+        let page = client.get("/2025/day/4").unwrap();
+        let block = &extract_blocks(&page)[0];
+        let mut examples = Examples::load(directory.path()).unwrap();
+
+        save_example(&mut examples, "example".to_string(), block).unwrap();
+        examples.save().unwrap();
+
+        let examples = Examples::load(directory.path()).unwrap();
+        let example = examples.get("example").unwrap();
+
+        assert_eq!(server.requests()[0].path, "/2025/day/4");
+        assert_eq!(examples.input(example).unwrap(), "..@@.\n@@@.@\n");
+        assert_eq!(example.part1, Some(Answer::Number(13)));
+        assert_eq!(example.part2, Some(Answer::Number(43)));
+    }
+
+    #[test]
+    fn it_refuses_to_save_examples_of_days_without_a_crate() {
+        let directory = tempfile::tempdir().unwrap();
+        let page = directory.path().join("page.html");
+        fs::write(&page, PAGE).unwrap();
+
+        let args = ExtractArgs {
+            year: Some(2025),
+            day: 9,
+            page: Some(page),
+            pick: vec![1],
+            name: Vec::new(),
+        };
+        let config = Config {
+            workspace: Workspace::new(directory.path()),
+            ..Config::default()
+        };
+
+        assert!(matches!(
+            extract(&args, &Registry::default(), &config),
+            Err(Error::UnknownDay { year: 2025, day: 9 })
+        ));
+        assert!(!directory.path().join("day09").exists());
+    }
+}
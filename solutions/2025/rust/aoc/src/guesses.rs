@@ -0,0 +1,236 @@
+use crate::{error::Error, state, submit::Verdict};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GuessLog {
+    #[serde(default, rename = "guess")]
+    guesses: Vec<Guess>,
+}
+
+/// Exclusive bounds on a numeric answer, derived from answers that were too high or too low.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bounds {
+    pub above: Option<i128>,
+    pub below: Option<i128>,
+}
+
+impl Bounds {
+    pub fn contains(&self, value: i128) -> bool {
+        self.above.is_none_or(|above| value > above) && self.below.is_none_or(|below| value < below)
+    }
+}
+
+impl Display for Bounds {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => write!(formatter, "above {above} and below {below}"),
+            (Some(above), None) => write!(formatter, "above {above}"),
+            (None, Some(below)) => write!(formatter, "below {below}"),
+            (None, None) => write!(formatter, "anything"),
+        }
+    }
+}
+
+/// Every answer submitted for a day along with the verdict it got.
+pub struct GuessHistory {
+    path: PathBuf,
+    log: GuessLog,
+}
+
+impl GuessHistory {
+    pub fn load(state_dir: &Path, year: u16, day: u8) -> Result<Self, Error> {
+        let path = state_dir.join(format!("guesses/{year}/day{day:02}.toml"));
+        let log = state::read(&path)?;
+
+        Ok(Self { path, log })
+    }
+
+    pub fn guesses(&self, part: u8) -> impl Iterator<Item = &Guess> {
+        self.log
+            .guesses
+            .iter()
+            .filter(move |guess| guess.part == part)
+    }
+
+    /// Whether a part was accepted, either on submission or by the site reporting it solved.
+    pub fn solved(&self, part: u8) -> bool {
+        self.guesses(part)
+            .any(|guess| matches!(guess.verdict, Verdict::Correct | Verdict::AlreadySolved))
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) -> Result<(), Error> {
+        self.log.guesses.push(Guess {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+
+        state::write(&self.path, &self.log)
+    }
+
+    pub fn bounds(&self, part: u8) -> Bounds {
+        let mut bounds = Bounds::default();
+
+        for guess in self.guesses(part) {
+            let Ok(value) = guess.answer.parse::<i128>() else {
+                continue;
+            };
+
+            match guess.verdict {
+                Verdict::TooLow => bounds.above = bounds.above.max(Some(value)),
+                Verdict::TooHigh => {
+                    bounds.below = Some(bounds.below.map_or(value, |below| below.min(value)))
+                }
+                _ => {}
+            }
+        }
+
+        bounds
+    }
+
+    /// Refuses answers that were rejected before or that fall outside of the known bounds.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Error> {
+        let rejected = self.guesses(part).find(|guess| {
+            guess.answer == answer
+                && matches!(
+                    guess.verdict,
+                    Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+                )
+        });
+
+        if let Some(guess) = rejected {
+            return Err(Error::KnownWrongAnswer {
+                answer: answer.to_string(),
+                verdict: guess.verdict,
+            });
+        }
+
+        let bounds = self.bounds(part);
+
+        match answer.parse::<i128>() {
+            Ok(value) if !bounds.contains(value) => Err(Error::OutOfBounds {
+                answer: answer.to_string(),
+                bounds,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(guesses: &[(u8, &str, Verdict)]) -> GuessHistory {
+        let directory = tempfile::tempdir().unwrap();
+        let mut history = GuessHistory::load(directory.path(), 2025, 1).unwrap();
+
+        for &(part, answer, verdict) in guesses {
+            history.record(part, answer, verdict).unwrap();
+        }
+
+        history
+    }
+
+    #[test]
+    fn it_derives_bounds_from_verdicts() {
+        let history = history(&[
+            (1, "100", Verdict::TooLow),
+            (1, "500", Verdict::TooHigh),
+            (1, "200", Verdict::TooLow),
+            (1, "450", Verdict::TooHigh),
+            (1, "300", Verdict::Wrong),
+            (2, "5", Verdict::TooHigh),
+        ]);
+
+        let bounds = history.bounds(1);
+
+        assert_eq!(
+            bounds,
+            Bounds {
+                above: Some(200),
+                below: Some(450)
+            }
+        );
+        assert!(bounds.contains(201) && bounds.contains(449));
+        assert!(!bounds.contains(200) && !bounds.contains(450));
+        assert_eq!(
+            history.bounds(2),
+            Bounds {
+                above: None,
+                below: Some(5)
+            }
+        );
+    }
+
+    #[test]
+    fn it_refuses_rejected_and_infeasible_answers() {
+        let history = history(&[
+            (1, "200", Verdict::TooLow),
+            (1, "300", Verdict::Wrong),
+            (1, "350", Verdict::RateLimited),
+        ]);
+
+        assert!(matches!(
+            history.check(1, "300"),
+            Err(Error::KnownWrongAnswer {
+                verdict: Verdict::Wrong,
+                ..
+            })
+        ));
+        assert!(matches!(
+            history.check(1, "150"),
+            Err(Error::OutOfBounds { .. })
+        ));
+        assert!(history.check(1, "350").is_ok());
+        assert!(history.check(1, "abc").is_ok());
+        assert!(history.check(2, "300").is_ok());
+    }
+
+    #[test]
+    fn it_knows_which_parts_are_solved() {
+        let history = history(&[
+            (1, "200", Verdict::TooLow),
+            (1, "250", Verdict::Correct),
+            (2, "300", Verdict::RateLimited),
+        ]);
+
+        assert!(history.solved(1));
+        assert!(!history.solved(2));
+
+        let history = self::history(&[(2, "300", Verdict::AlreadySolved)]);
+        assert!(history.solved(2));
+    }
+
+    #[test]
+    fn it_persists_guesses() {
+        let directory = tempfile::tempdir().unwrap();
+
+        GuessHistory::load(directory.path(), 2025, 4)
+            .unwrap()
+            .record(2, "9000", Verdict::TooLow)
+            .unwrap();
+
+        let history = GuessHistory::load(directory.path(), 2025, 4).unwrap();
+
+        assert_eq!(
+            history.guesses(2).collect::<Vec<_>>(),
+            [&Guess {
+                part: 2,
+                answer: "9000".to_string(),
+                verdict: Verdict::TooLow
+            }]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Just enough HTML handling to pull text out of the website's pages.
+
+/// The text in front of the first `end`, starting after the closest `start` before it.
+pub fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (before, _) = text.split_once(end)?;
+    before.rsplit_once(start).map(|(_, inner)| inner)
+}
+
+/// The contents of every `<article>` on a page, in order.
+pub fn articles(page: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = page;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let contents = &rest[start + open_end + 1..];
+        let end = contents.find("</article>").unwrap_or(contents.len());
+
+        articles.push(&contents[..end]);
+        rest = &contents[end..];
+    }
+
+    articles
+}
+
+/// Removes every tag and decodes the entities the website uses.
+pub fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    decode_entities(&text)
+}
+
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_extracts_text_and_articles() {
+        let page = r#"<main><article class="day-desc"><p>a &lt; <em>b</em></p></article>
+            <p>between</p><article><p>c &amp;amp;</p></article></main>"#;
+
+        let articles = articles(page);
+
+        assert_eq!(articles.len(), 2);
+        assert_eq!(text(articles[0]), "a < b");
+        assert_eq!(text(articles[1]), "c &amp;");
+        assert_eq!(between(page, "<p>", "</p>"), Some("a &lt; <em>b</em>"));
+    }
+}
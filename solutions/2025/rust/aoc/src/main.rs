@@ -0,0 +1,148 @@
+use crate::{
+    bench::BenchArgs, client::Client, download::DownloadArgs, encrypt::EncryptArgs, error::Error,
+    extract::ExtractArgs, puzzle::PuzzleArgs, run::RunArgs, scaffold::NewArgs, submit::SubmitArgs,
+    verify::VerifyArgs,
+};
+use aoc_common::{Config, InputProvider, KnownAnswers, Registry, Workspace};
+use clap::{Parser, Subcommand};
+use std::{path::PathBuf, process::ExitCode};
+
+mod bench;
+mod calendar;
+mod client;
+mod cooldown;
+mod download;
+mod encrypt;
+mod error;
+mod extract;
+mod guesses;
+mod html;
+mod parallel;
+mod puzzle;
+mod run;
+mod scaffold;
+mod state;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod verify;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc_common::allocations::CountingAllocator =
+    aoc_common::allocations::CountingAllocator;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs Advent Of Code solutions")]
+struct Cli {
+    /// Directory to look up inputs in, overriding AOC_INPUT_DIR and the config file.
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+    /// Workspace of the solutions, overriding AOC_WORKSPACE and the search upwards from the
+    /// current directory.
+    #[arg(long, global = true)]
+    workspace: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run one or more days and print their answers.
+    Run(RunArgs),
+    /// Download the input of a day into the inputs directory.
+    Download(DownloadArgs),
+    /// Submit the answer to a part of a day.
+    Submit(SubmitArgs),
+    /// Re-run every registered day and compare the answers against the known answers.
+    Verify(VerifyArgs),
+    /// Replace the plain answers in the answers file with salted hashes keyed with the key file.
+    HashAnswers,
+    /// Encrypt the inputs directory with the key in the key file.
+    Encrypt(EncryptArgs),
+    /// Write plain copies of the encrypted inputs.
+    Decrypt,
+    /// Extract examples from a puzzle description into the day's examples manifest.
+    Extract(ExtractArgs),
+    /// Archive the description of a day and render it in the terminal.
+    Puzzle(PuzzleArgs),
+    /// Create the crate of a new day and add it to the workspace.
+    New(NewArgs),
+    /// Time the phases of one or more days.
+    Bench(BenchArgs),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let registry = aoc2025::registry();
+
+    match execute(&cli, &registry) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(cli: &Cli, registry: &Registry) -> Result<bool, Error> {
+    let config = Config::load(Workspace::locate(cli.workspace.clone())?)?;
+    let inputs = InputProvider::with_config(cli.input_dir.clone(), &config);
+
+    match &cli.command {
+        Command::Run(args) => run::run(args, registry, &inputs, &config),
+        Command::Download(args) => {
+            download::download(args, registry, &Client::from_config(&config)?, &inputs)
+        }
+        Command::Submit(args) => submit::submit(
+            args,
+            registry,
+            &Client::from_config(&config)?,
+            &inputs,
+            &config.state_dir(),
+        ),
+        Command::Verify(args) => {
+            verify::verify(args, registry, &inputs, &KnownAnswers::load(&config)?)
+        }
+        Command::HashAnswers => {
+            verify::hash_answers(&KnownAnswers::path(&config), &config.key_file())
+        }
+        Command::Encrypt(args) => encrypt::encrypt(args, &inputs),
+        Command::Decrypt => encrypt::decrypt(&inputs),
+        Command::Extract(args) => extract::extract(args, registry, &config),
+        Command::Puzzle(args) => puzzle::puzzle(args, registry, &config),
+        Command::New(args) => scaffold::new(args, registry, &config.workspace),
+        Command::Bench(args) => bench::bench(args, registry, &inputs, &config.state_dir()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_days_and_years_outside_of_the_calendar() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["aoc"], args].concat());
+
+        assert!(parse(&["download", "--day", "25"]).is_ok());
+        assert!(parse(&["download", "--day", "0"]).is_err());
+        assert!(parse(&["submit", "--day", "200", "--part", "1"]).is_err());
+        assert!(parse(&["puzzle", "--day", "4", "--year", "1969"]).is_err());
+        assert!(parse(&["extract", "--day", "26"]).is_err());
+        assert!(parse(&["run", "--day", "4", "--year", "2015"]).is_ok());
+    }
+
+    #[test]
+    fn it_rejects_names_that_are_not_file_names() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["aoc"], args].concat());
+
+        assert!(parse(&["extract", "--day", "4", "--name", "example,larger"]).is_ok());
+        assert!(parse(&["extract", "--day", "4", "--name", "../../escape"]).is_err());
+        assert!(parse(&["extract", "--day", "4", "--name", "example,nested/name"]).is_err());
+        assert!(parse(&["extract", "--day", "4", "--name", ".."]).is_err());
+        assert!(parse(&["bench", "--save-baseline", "main", "--compare", "v1.2"]).is_ok());
+        assert!(parse(&["bench", "--save-baseline", "../../inputs/day01"]).is_err());
+        assert!(parse(&["bench", "--compare", "/etc/passwd"]).is_err());
+    }
+}
@@ -0,0 +1,336 @@
+use crate::{
+    bench::{Phase, format_nanos},
+    error::Error,
+    run,
+    verify::{self, Status},
+};
+use aoc_common::{
+    Answer, ExpectedAnswer, InputProvider, KnownAnswers, solution::DynSolution, trace,
+};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+struct DayReport {
+    day: u8,
+    result: Result<Vec<PhaseReport>, Error>,
+}
+
+struct PhaseReport {
+    phase: Phase,
+    elapsed: Duration,
+    /// The answer of a part and how it compares to the known answer, `None` for the parse step.
+    result: Result<Option<(Answer, Status)>, Error>,
+}
+
+#[derive(Debug, Default)]
+struct Summary {
+    matching: usize,
+    mismatched: usize,
+    unknown: usize,
+    failed: usize,
+}
+
+/// Runs the days on their actual inputs on up to `jobs` threads and prints a table of their
+/// answers, timings and errors once all of them finished, returning whether none of them failed
+/// or produced an answer other than the known one.
+pub fn run(
+    solutions: &[&dyn DynSolution],
+    jobs: usize,
+    part: Option<u8>,
+    inputs: &InputProvider,
+    answers: &KnownAnswers,
+) -> bool {
+    let started = Instant::now();
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(solutions.len()) {
+            let (sender, next) = (sender.clone(), &next);
+
+            scope.spawn(move || {
+                while let Some(&solution) = solutions.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                        run_day(solution, part, inputs, answers)
+                    }))
+                    .unwrap_or(Err(Error::Panicked));
+
+                    let report = DayReport {
+                        day: solution.day(),
+                        result,
+                    };
+
+                    if sender.send(report).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    drop(sender);
+
+    let mut reports = receiver.into_iter().collect::<Vec<_>>();
+    reports.sort_by_key(|report| report.day);
+
+    let (lines, summary) = table(&reports);
+
+    for line in lines {
+        println!("{}", line.trim_end());
+    }
+
+    println!(
+        "{} days in {}: {} matching, {} mismatched, {} unknown, {} failed",
+        reports.len(),
+        format_nanos(started.elapsed().as_nanos() as f64),
+        summary.matching,
+        summary.mismatched,
+        summary.unknown,
+        summary.failed
+    );
+
+    summary.mismatched == 0 && summary.failed == 0
+}
+
+fn run_day(
+    solution: &dyn DynSolution,
+    part: Option<u8>,
+    inputs: &InputProvider,
+    answers: &KnownAnswers,
+) -> Result<Vec<PhaseReport>, Error> {
+    let (year, day) = (solution.year(), solution.day());
+
+    let _span = trace::span(format!("day {day:02}"));
+    let input = run::load_input(|| inputs.load(year, day).map_err(Error::from))?;
+
+    let parse_span = trace::span("parse");
+    let start = Instant::now();
+    let parsed = solution.parse(&input);
+    let elapsed = start.elapsed();
+    drop(parse_span);
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            return Ok(vec![PhaseReport {
+                phase: Phase::Parse,
+                elapsed,
+                result: Err(Error::Solution(error)),
+            }]);
+        }
+    };
+
+    let mut reports = vec![PhaseReport {
+        phase: Phase::Parse,
+        elapsed,
+        result: Ok(None),
+    }];
+
+    for (current, phase) in [(1, Phase::Part1), (2, Phase::Part2)] {
+        if part.is_some_and(|part| part != current) {
+            continue;
+        }
+
+        let _span = trace::span(phase.to_string());
+        let start = Instant::now();
+
+        let answer = match current {
+            1 => solution.part1(parsed.as_ref()).map(Some),
+            _ => solution.part2(parsed.as_ref()),
+        };
+
+        let elapsed = start.elapsed();
+
+        let result = match answer {
+            Ok(Some(answer)) => {
+                let status = verify::status(solution, current, &input, &answer, answers);
+                Ok(Some((answer, status)))
+            }
+            Ok(None) if part.is_some() => Err(Error::MissingPart2 { year, day }),
+            Ok(None) => continue,
+            Err(error) => Err(Error::Solution(error)),
+        };
+
+        reports.push(PhaseReport {
+            phase,
+            elapsed,
+            result,
+        });
+    }
+
+    Ok(reports)
+}
+
+/// Formats a row for every phase of the reports and counts how their answers compare to the
+/// known answers.
+fn table(reports: &[DayReport]) -> (Vec<String>, Summary) {
+    let mut summary = Summary::default();
+
+    let width = reports
+        .iter()
+        .filter_map(|report| report.result.as_ref().ok())
+        .flatten()
+        .filter_map(|phase| phase.result.as_ref().ok()?.as_ref())
+        .map(|(answer, _)| answer.to_string().len())
+        .fold("answer".len(), usize::max);
+
+    let mut lines = vec![format!(
+        "{:<4} {:<7} {:<width$} {:>10} status",
+        "day", "phase", "answer", "time"
+    )];
+
+    for report in reports {
+        let day = format!("{:02}", report.day);
+
+        let phases = match &report.result {
+            Ok(phases) => phases,
+            Err(error) => {
+                lines.push(format!(
+                    "{day:<4} {:<7} {:<width$} {:>10} error: {error}",
+                    "-", "", ""
+                ));
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for PhaseReport {
+            phase,
+            elapsed,
+            result,
+        } in phases
+        {
+            let (answer, status) = match result {
+                Ok(None) => (String::new(), String::new()),
+                Ok(Some((answer, status))) => {
+                    let status = match status {
+                        Status::Matches => {
+                            summary.matching += 1;
+                            "ok".to_string()
+                        }
+                        Status::Regressed {
+                            expected: ExpectedAnswer::Plain(expected),
+                        } => {
+                            summary.mismatched += 1;
+                            format!("mismatch, expected {expected}")
+                        }
+                        Status::Regressed { .. } => {
+                            summary.mismatched += 1;
+                            "mismatch".to_string()
+                        }
+                        Status::Unknown => {
+                            summary.unknown += 1;
+                            "unknown".to_string()
+                        }
+                        Status::Unverifiable => {
+                            summary.unknown += 1;
+                            "unverifiable".to_string()
+                        }
+                    };
+
+                    (answer.to_string(), status)
+                }
+                Err(error) => {
+                    summary.failed += 1;
+                    (String::new(), format!("error: {error}"))
+                }
+            };
+
+            lines.push(format!(
+                "{day:<4} {:<7} {answer:<width$} {:>10} {status}",
+                phase.to_string(),
+                format_nanos(elapsed.as_nanos() as f64)
+            ));
+        }
+    }
+
+    (lines, summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_tabulates_answers_and_errors() {
+        let phase = |phase, micros, result| PhaseReport {
+            phase,
+            elapsed: Duration::from_micros(micros),
+            result,
+        };
+
+        let reports = [
+            DayReport {
+                day: 1,
+                result: Ok(vec![
+                    phase(Phase::Parse, 5, Ok(None)),
+                    phase(
+                        Phase::Part1,
+                        10,
+                        Ok(Some((Answer::from(3), Status::Matches))),
+                    ),
+                    phase(
+                        Phase::Part2,
+                        20,
+                        Ok(Some((
+                            Answer::from(1234567),
+                            Status::Regressed {
+                                expected: ExpectedAnswer::Plain(Answer::from(6)),
+                            },
+                        ))),
+                    ),
+                ]),
+            },
+            DayReport {
+                day: 2,
+                result: Err(Error::MissingPart2 { year: 2025, day: 2 }),
+            },
+            DayReport {
+                day: 3,
+                result: Ok(vec![
+                    phase(Phase::Parse, 1, Ok(None)),
+                    phase(
+                        Phase::Part1,
+                        2,
+                        Ok(Some((Answer::from(7), Status::Unknown))),
+                    ),
+                    phase(Phase::Part2, 3, Err(Error::Panicked)),
+                ]),
+            },
+        ];
+
+        let (lines, summary) = table(&reports);
+        let lines = lines.iter().map(|line| line.trim_end()).collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            [
+                "day  phase   answer        time status",
+                "01   parse               5.00µs",
+                "01   part 1  3          10.00µs ok",
+                "01   part 2  1234567    20.00µs mismatch, expected 6",
+                "02   -                          error: day 2 of 2025 has no second part",
+                "03   parse               1.00µs",
+                "03   part 1  7           2.00µs unknown",
+                "03   part 2              3.00µs error: the solution panicked",
+            ]
+        );
+
+        assert_eq!(
+            (
+                summary.matching,
+                summary.mismatched,
+                summary.unknown,
+                summary.failed
+            ),
+            (1, 1, 1, 2)
+        );
+    }
+}
@@ -0,0 +1,439 @@
+use crate::{
+    client::Client,
+    download::{self, Download},
+    error::Error,
+    guesses::GuessHistory,
+    html,
+};
+use aoc_common::{Config, Registry};
+use clap::Args;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+#[derive(Debug, Args)]
+pub struct PuzzleArgs {
+    /// Year of the puzzle, defaults to the most recent registered year.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Day of the puzzle.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Render the description as Markdown instead of plain text.
+    #[arg(short, long)]
+    markdown: bool,
+    /// Only use the archived description, even when it does not have the second part yet.
+    #[arg(long, conflicts_with = "refresh")]
+    offline: bool,
+    /// Fetch the description again even when it is archived already.
+    #[arg(long)]
+    refresh: bool,
+    /// Write the description as Markdown to `puzzle.md` in the day's crate instead of printing it.
+    #[arg(long)]
+    save: bool,
+}
+
+/// When to fetch a description that is archived already.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Refresh {
+    Never,
+    /// Only when the archived description does not have the second part, which shows up once the
+    /// first part is solved.
+    IfIncomplete,
+    Always,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Markdown,
+}
+
+/// Puzzle descriptions saved by year and day, laid out as `<year>/day<day>.html` like the inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleArchive {
+    directory: PathBuf,
+}
+
+impl PuzzleArchive {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.directory.join(format!("{year}/day{day:02}.html"))
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<Option<String>, Error> {
+        let path = self.path(year, day);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        fs::read_to_string(&path)
+            .map(Some)
+            .map_err(|source| Error::ReadPage { path, source })
+    }
+}
+
+pub fn puzzle(args: &PuzzleArgs, registry: &Registry, config: &Config) -> Result<bool, Error> {
+    let year = args
+        .year
+        .or(registry.latest_year())
+        .ok_or(Error::NoSolutions)?;
+
+    let archive = PuzzleArchive::new(config.puzzle_dir());
+
+    if !args.offline {
+        let refresh = if args.refresh {
+            Refresh::Always
+        } else if GuessHistory::load(&config.state_dir(), year, args.day)?.solved(1) {
+            Refresh::IfIncomplete
+        } else {
+            Refresh::Never
+        };
+
+        download_puzzle(
+            &Client::from_config(config)?,
+            &archive,
+            (year, args.day),
+            refresh,
+            SystemTime::now(),
+        )?;
+    }
+
+    let page = archive
+        .load(year, args.day)?
+        .ok_or(Error::PuzzleNotArchived {
+            year,
+            day: args.day,
+            path: archive.path(year, args.day),
+        })?;
+
+    if args.save {
+        let path = config.workspace.day_dir(args.day).join("puzzle.md");
+        write_description(&path, &render(&page, Format::Markdown))?;
+        println!(
+            "day {:02}: saved description to '{}'",
+            args.day,
+            path.display()
+        );
+    } else if args.markdown {
+        print!("{}", render(&page, Format::Markdown));
+    } else {
+        print!("{}", render(&page, Format::Text));
+    }
+
+    Ok(true)
+}
+
+/// Archives the description of a day, fetching one that is archived already only as `refresh`
+/// allows.
+pub fn download_puzzle(
+    client: &Client,
+    archive: &PuzzleArchive,
+    (year, day): (u16, u8),
+    refresh: Refresh,
+    now: SystemTime,
+) -> Result<Download, Error> {
+    let path = archive.path(year, day);
+
+    if let Some(page) = archive.load(year, day)? {
+        let fetch = match refresh {
+            Refresh::Never => false,
+            Refresh::IfIncomplete => html::articles(&page).len() < 2,
+            Refresh::Always => true,
+        };
+
+        if !fetch {
+            return Ok(Download::Cached(path));
+        }
+    }
+
+    download::fetch(
+        client,
+        &format!("/{year}/day/{day}"),
+        path,
+        (year, day),
+        now,
+    )
+}
+
+/// Renders the articles of a puzzle description, leaving out the rest of the page such as the
+/// answers that were already given.
+pub fn render(page: &str, format: Format) -> String {
+    let mut output = String::new();
+
+    for article in html::articles(page) {
+        render_article(article, format, &mut output);
+    }
+
+    let mut rendered = output
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+
+    while rendered.contains("\n\n\n") {
+        rendered = rendered.replace("\n\n\n", "\n\n");
+    }
+
+    rendered + "\n"
+}
+
+fn render_article(article: &str, format: Format, output: &mut String) {
+    let markdown = format == Format::Markdown;
+    let mut pre = None::<String>;
+    let mut links = Vec::new();
+    let mut emphasised_code = false;
+    let mut reopened_code = false;
+    let mut rest = article;
+
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(start) => {
+                let end = rest[start..]
+                    .find('>')
+                    .map_or(rest.len(), |end| start + end + 1);
+                let tag = &rest[start..end];
+                let text = &rest[..start];
+                rest = &rest[end..];
+                (text, Some(tag))
+            }
+            None => {
+                let text = rest;
+                rest = "";
+                (text, None)
+            }
+        };
+
+        let text = html::decode_entities(text);
+
+        match &mut pre {
+            Some(code) => code.push_str(&text),
+            None => push_text(output, &text),
+        }
+
+        let Some(tag) = tag else {
+            continue;
+        };
+
+        let closing = tag.starts_with("</");
+        let name = tag
+            .trim_start_matches("</")
+            .trim_start_matches('<')
+            .trim_end_matches('>')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+
+        if let Some(code) = &pre {
+            if closing && name == "pre" {
+                if markdown {
+                    output.push_str(&format!("```\n{}\n```\n\n", code.trim_end()));
+                } else {
+                    for line in code.trim_end().lines() {
+                        output.push_str(&format!("    {line}\n"));
+                    }
+
+                    output.push('\n');
+                }
+
+                pre = None;
+            }
+
+            continue;
+        }
+
+        match (name, closing) {
+            ("pre", false) => pre = Some(String::new()),
+            ("h2", false) if markdown => output.push_str("## "),
+            ("h2" | "p" | "ul", true) => output.push_str("\n\n"),
+            ("li", false) => output.push_str("- "),
+            ("li", true) => output.push('\n'),
+            // Markdown does not render emphasis inside code, so emphasise the code instead.
+            ("em", false) if markdown && output.ends_with('`') => {
+                output.pop();
+                output.push_str("**`");
+                emphasised_code = true;
+            }
+            // Close the emphasised code and reopen it for any code that follows the emphasis,
+            // which is dropped again if the code ends right away.
+            ("em", true) if markdown && emphasised_code => {
+                output.push_str("`**`");
+                emphasised_code = false;
+                reopened_code = true;
+            }
+            ("code", true) if markdown && reopened_code => {
+                if output.ends_with("`**`") {
+                    output.pop();
+                } else {
+                    output.push('`');
+                }
+
+                reopened_code = false;
+            }
+            ("code", _) if markdown => output.push('`'),
+            ("em", _) if markdown => output.push_str("**"),
+            ("a", false) if markdown => {
+                links.push(attribute(tag, "href").unwrap_or_default().to_string());
+                output.push('[');
+            }
+            ("a", true) if markdown => {
+                output.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Appends text outside of code blocks with its whitespace collapsed like a browser would.
+fn push_text(output: &mut String, text: &str) {
+    let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if collapsed.is_empty() {
+        if !text.is_empty() && !output.is_empty() && !output.ends_with(['\n', ' ']) {
+            output.push(' ');
+        }
+
+        return;
+    }
+
+    if text.starts_with(char::is_whitespace) && !output.is_empty() && !output.ends_with('\n') {
+        collapsed.insert(0, ' ');
+    }
+
+    if text.ends_with(char::is_whitespace) {
+        collapsed.push(' ');
+    }
+
+    output.push_str(&collapsed);
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let (_, value) = tag.split_once(&format!("{name}=\""))?;
+    value.split_once('"').map(|(value, _)| value)
+}
+
+fn write_description(path: &Path, description: &str) -> Result<(), Error> {
+    fs::write(path, description).map_err(|source| Error::WriteDescription {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calendar, stub::StubServer};
+    use std::time::Duration;
+
+    const PART1: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 6: Trash Compactor ---</h2>
+<p>Each problem's numbers are <em>vertical</em>; see the <a href="/2025/day/6/input">input</a>.</p>
+<pre><code>123 328
+ 45 64
+*   +
+</code></pre>
+<ul>
+<li><code>123 * 45</code> = <code>5535</code></li>
+</ul>
+<p>The grand total is <code><em>4277556</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>123456</code>.</p>
+</main>"#;
+
+    fn after_unlock() -> SystemTime {
+        calendar::unlock_time(2025, 6).unwrap() + Duration::from_secs(60)
+    }
+
+    #[test]
+    fn it_renders_descriptions_as_text() {
+        assert_eq!(
+            render(PART1, Format::Text),
+            "--- Day 6: Trash Compactor ---\n\n\
+             Each problem's numbers are vertical; see the input.\n\n    \
+             123 328\n     45 64\n    *   +\n\n\
+             - 123 * 45 = 5535\n\n\
+             The grand total is 4277556.\n"
+        );
+    }
+
+    #[test]
+    fn it_renders_descriptions_as_markdown() {
+        assert_eq!(
+            render(PART1, Format::Markdown),
+            "## --- Day 6: Trash Compactor ---\n\n\
+             Each problem's numbers are **vertical**; see the [input](/2025/day/6/input).\n\n\
+             ```\n123 328\n 45 64\n*   +\n```\n\n\
+             - `123 * 45` = `5535`\n\n\
+             The grand total is **`4277556`**.\n"
+        );
+    }
+
+    #[test]
+    fn it_renders_code_that_continues_after_its_emphasis() {
+        assert_eq!(
+            render(
+                "<article><p>Turn <code><em>1</em>→</code> or <code><em>2</em></code>.</p></article>",
+                Format::Markdown
+            ),
+            "Turn **`1`**`→` or **`2`**.\n"
+        );
+    }
+
+    #[test]
+    fn it_reports_descriptions_that_cannot_be_saved() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("day09/puzzle.md");
+
+        assert!(matches!(
+            write_description(&path, "--- Day 9 ---\n"),
+            Err(Error::WriteDescription { path: failed, .. }) if failed == path
+        ));
+    }
+
+    #[test]
+    fn it_fetches_archived_descriptions_only_when_refreshing() {
+        let both_parts = format!("{PART1}<article><h2>--- Part Two ---</h2></article>");
+        let server = StubServer::start(vec![
+            (200, PART1.to_string()),
+            (200, both_parts.clone()),
+            (200, both_parts.clone()),
+        ]);
+        let client = Client::new(server.url(), Some("cookie".to_string()));
+        let directory = tempfile::tempdir().unwrap();
+        let archive = PuzzleArchive::new(directory.path());
+        let path = archive.path(2025, 6);
+
+        let download = |refresh| {
+            download_puzzle(&client, &archive, (2025, 6), refresh, after_unlock()).unwrap()
+        };
+
+        assert_eq!(download(Refresh::Never), Download::Fetched(path.clone()));
+        assert_eq!(download(Refresh::Never), Download::Cached(path.clone()));
+        assert_eq!(server.requests().len(), 1);
+
+        assert_eq!(
+            download(Refresh::IfIncomplete),
+            Download::Fetched(path.clone())
+        );
+        assert_eq!(
+            download(Refresh::IfIncomplete),
+            Download::Cached(path.clone())
+        );
+        assert_eq!(archive.load(2025, 6).unwrap(), Some(both_parts));
+        assert_eq!(server.requests().len(), 2);
+
+        assert_eq!(download(Refresh::Always), Download::Fetched(path));
+        assert_eq!(server.requests().len(), 3);
+        assert_eq!(server.requests()[0].path, "/2025/day/6");
+    }
+}
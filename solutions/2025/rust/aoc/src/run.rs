@@ -0,0 +1,289 @@
+use crate::{error::Error, parallel};
+use aoc_common::{
+    Answer, Config, Example, Examples, InputProvider, InputSource, KnownAnswers, Registry,
+    Workspace,
+    allocations::{self, Allocations},
+    solution::DynSolution,
+    trace,
+};
+use clap::{Args, builder::RangedU64ValueParser};
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+/// Whether the counting allocator is installed, in which case the allocations of every phase
+/// are reported next to its answer.
+const COUNT_ALLOCATIONS: bool = cfg!(feature = "count-allocations");
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// Year to run, defaults to the most recent registered year.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Day to run.
+    #[arg(
+        short,
+        long,
+        required_unless_present = "all",
+        value_parser = clap::value_parser!(u8).range(1..=25)
+    )]
+    day: Option<u8>,
+    /// Run every registered day of the year.
+    #[arg(short, long, conflicts_with = "day")]
+    all: bool,
+    /// Run the days on this many threads and compare their answers against the known answers,
+    /// printing a single table once every day finished.
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["day", "example"],
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    jobs: Option<usize>,
+    /// Only run a single part.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input from a file, or from stdin when given '-'.
+    #[arg(short, long, conflicts_with_all = ["all", "example"])]
+    input: Option<InputSource>,
+    /// Run on the day's examples instead of the actual input, or only on the named example.
+    #[arg(short, long, value_name = "NAME", num_args = 0..=1, default_missing_value = "")]
+    example: Option<String>,
+    /// Write the time spent loading, parsing and solving as a Chrome trace to this file.
+    #[arg(long, value_name = "PATH")]
+    trace: Option<PathBuf>,
+}
+
+/// Runs the selected days, returning whether every part completed successfully.
+pub fn run(
+    args: &RunArgs,
+    registry: &Registry,
+    inputs: &InputProvider,
+    config: &Config,
+) -> Result<bool, Error> {
+    let year = args
+        .year
+        .or(registry.latest_year())
+        .ok_or(Error::NoSolutions)?;
+
+    let solutions = match args.day {
+        Some(day) => vec![
+            registry
+                .get(year, day)
+                .ok_or(Error::UnknownDay { year, day })?,
+        ],
+        None => registry.year(year).collect(),
+    };
+
+    if solutions.is_empty() {
+        return Err(Error::UnknownYear(year));
+    }
+
+    if args.trace.is_some() {
+        trace::start();
+    }
+
+    let success = match args.jobs {
+        Some(jobs) => {
+            let answers = KnownAnswers::load(config)?;
+            parallel::run(&solutions, jobs, args.part, inputs, &answers)
+        }
+        None => run_serially(args, &solutions, year, inputs, &config.workspace),
+    };
+
+    if let Some(path) = &args.trace {
+        write_trace(path, &trace::finish())?;
+    }
+
+    Ok(success)
+}
+
+fn run_serially(
+    args: &RunArgs,
+    solutions: &[&dyn DynSolution],
+    year: u16,
+    inputs: &InputProvider,
+    workspace: &Workspace,
+) -> bool {
+    let mut success = true;
+
+    for &solution in solutions {
+        let day = solution.day();
+        let _span = trace::span(format!("day {day:02}"));
+
+        let result = match (&args.input, &args.example) {
+            (Some(source), _) => load_input(|| source.read().map_err(Error::from))
+                .and_then(|input| run_day(solution, &input, args.part, None)),
+            (None, Some(name)) => run_examples(solution, name, args.part, workspace),
+            (None, None) => load_input(|| inputs.load(year, day).map_err(Error::from))
+                .and_then(|input| run_day(solution, &input, args.part, None)),
+        };
+
+        match result {
+            Ok(matches) => success &= matches,
+            Err(error) => {
+                eprintln!("day {day:02}: error: {error}");
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+pub fn load_input(load: impl FnOnce() -> Result<String, Error>) -> Result<String, Error> {
+    let _span = trace::span("load input");
+    load()
+}
+
+fn write_trace(path: &Path, trace: &trace::Trace) -> Result<(), Error> {
+    let write_error = |source| Error::WriteTrace {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let mut writer = BufWriter::new(File::create(path).map_err(write_error)?);
+    serde_json::to_writer(&mut writer, trace).map_err(|error| write_error(error.into()))?;
+    writer
+        .into_inner()
+        .map_err(|error| write_error(error.into_error()))?;
+
+    Ok(())
+}
+
+/// Runs the examples of a day, or only the one with the given name unless it is empty.
+fn run_examples(
+    solution: &dyn DynSolution,
+    name: &str,
+    part: Option<u8>,
+    workspace: &Workspace,
+) -> Result<bool, Error> {
+    let day = solution.day();
+    let examples = Examples::load(&workspace.day_dir(day).join("data"))?;
+
+    let selected = if name.is_empty() {
+        examples.examples().collect::<Vec<_>>()
+    } else {
+        let example = examples.get(name).ok_or_else(|| Error::UnknownExample {
+            day,
+            name: name.to_string(),
+        })?;
+
+        vec![example]
+    };
+
+    if selected.is_empty() {
+        return Err(Error::NoExamples { day });
+    }
+
+    let mut matches = true;
+
+    for example in selected {
+        let input = load_input(|| Ok(examples.input(example)?))?;
+        matches &= run_day(solution, &input, part, Some(example))?;
+    }
+
+    Ok(matches)
+}
+
+/// Runs the selected parts on an input, returning whether they produced the answers the example
+/// expects, if the input is one.
+fn run_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    part: Option<u8>,
+    example: Option<&Example>,
+) -> Result<bool, Error> {
+    let (year, day) = (solution.year(), solution.day());
+    let label = example.map_or_else(String::new, |example| format!(" ({})", example.name));
+    let parse_span = trace::span("parse");
+    let (input, parsed) = allocations::measure(|| solution.parse(input));
+    drop(parse_span);
+    let input = input.map_err(Error::Solution)?;
+    let mut matches = true;
+
+    if COUNT_ALLOCATIONS {
+        println!("day {day:02} parse{label}:{}", format_allocations(parsed));
+    }
+
+    for current in 1..=2 {
+        if part.is_some_and(|part| part != current) {
+            continue;
+        }
+
+        let expected = example.map(|example| example.expected(current));
+
+        if expected == Some(None) {
+            println!("day {day:02} part {current}{label}: n/a");
+            continue;
+        }
+
+        let part_span = trace::span(format!("part {current}"));
+        let (answer, solved) = allocations::measure(|| match current {
+            1 => solution.part1(input.as_ref()).map(Some),
+            _ => solution.part2(input.as_ref()),
+        });
+        drop(part_span);
+
+        let Some(answer) = answer.map_err(Error::Solution)? else {
+            if part.is_some() {
+                return Err(Error::MissingPart2 { year, day });
+            }
+
+            continue;
+        };
+
+        let counted = if COUNT_ALLOCATIONS {
+            format_allocations(solved)
+        } else {
+            String::new()
+        };
+
+        match expected.flatten() {
+            Some(expected) if *expected != answer => {
+                println!(
+                    "day {day:02} part {current}{label}: {answer}, expected {expected}{counted}"
+                );
+                matches = false;
+            }
+            _ => println!("day {day:02} part {current}{label}: {answer}{counted}"),
+        }
+    }
+
+    Ok(matches)
+}
+
+fn format_allocations(allocations: Allocations) -> String {
+    format!(
+        " [{} allocations, {} allocated, {} peak]",
+        allocations.count,
+        format_bytes(allocations.bytes),
+        format_bytes(allocations.peak)
+    )
+}
+
+fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+
+    match bytes {
+        ..1024.0 => format!("{bytes}B"),
+        ..1048576.0 => format!("{:.2}KiB", bytes / 1024.0),
+        ..1073741824.0 => format!("{:.2}MiB", bytes / 1048576.0),
+        _ => format!("{:.2}GiB", bytes / 1073741824.0),
+    }
+}
+
+/// Parses the input and solves a single part of it.
+pub fn solve(solution: &dyn DynSolution, input: &str, part: u8) -> Result<Answer, Error> {
+    let (year, day) = (solution.year(), solution.day());
+    let input = solution.parse(input).map_err(Error::Solution)?;
+
+    match part {
+        1 => solution.part1(input.as_ref()).map(Some),
+        _ => solution.part2(input.as_ref()),
+    }
+    .map_err(Error::Solution)?
+    .ok_or(Error::MissingPart2 { year, day })
+}
@@ -0,0 +1,335 @@
+use crate::error::Error;
+use aoc_common::{Registry, Workspace, examples::EXAMPLES_FILE_NAME};
+use clap::Args;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.tmpl");
+const ERROR_TEMPLATE: &str = include_str!("../templates/day/error.rs.tmpl");
+const EXAMPLES_TEMPLATE: &str = include_str!("../templates/day/examples.toml.tmpl");
+
+#[derive(Debug, Args)]
+pub struct NewArgs {
+    /// Year of the day, defaults to the most recent registered year.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Day to create a crate for.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+pub fn new(args: &NewArgs, registry: &Registry, workspace: &Workspace) -> Result<bool, Error> {
+    let year = args
+        .year
+        .or(registry.latest_year())
+        .ok_or(Error::NoSolutions)?;
+
+    let created = scaffold(workspace.dir(), year, args.day)?;
+
+    for path in created {
+        println!("created '{}'", path.display());
+    }
+
+    Ok(true)
+}
+
+/// Creates the crate of a day from the template and adds it to the workspace and to the crate
+/// that registers the days of its year. Every file is checked before any of them is written, so a
+/// failure leaves the workspace untouched.
+pub fn scaffold(workspace: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, Error> {
+    let name = format!("day{day:02}");
+    let crate_dir = workspace.join(&name);
+    let year_dir = workspace.join(format!("aoc{year}"));
+
+    if crate_dir.exists() {
+        return Err(Error::DayExists {
+            day,
+            path: crate_dir,
+        });
+    }
+
+    if !year_dir.exists() {
+        return Err(Error::UnknownYear(year));
+    }
+
+    let workspace_manifest = workspace.join("Cargo.toml");
+    let year_manifest = year_dir.join("Cargo.toml");
+    let year_lib = year_dir.join("src/lib.rs");
+
+    let edits = [
+        (
+            &workspace_manifest,
+            vec![
+                ("\"day", "", format!("\"{name}\",")),
+                ("day", "{ path", format!("{name} = {{ path = \"{name}\" }}")),
+            ],
+        ),
+        (
+            &year_manifest,
+            vec![
+                ("day", "[\"dep:", format!("{name} = [\"dep:{name}\"]")),
+                (
+                    "day",
+                    "{ workspace",
+                    format!("{name} = {{ workspace = true, optional = true }}"),
+                ),
+            ],
+        ),
+        (
+            &year_lib,
+            vec![
+                (
+                    "pub use day",
+                    "",
+                    format!("#[cfg(feature = \"{name}\")]\npub use {name};"),
+                ),
+                (
+                    "registry.register::<day",
+                    "",
+                    format!(
+                        "#[cfg(feature = \"{name}\")]\nregistry.register::<{name}::Day{day:02}>();"
+                    ),
+                ),
+                (
+                    "cfg!(feature = \"day",
+                    "",
+                    format!("cfg!(feature = \"{name}\"),"),
+                ),
+            ],
+        ),
+    ];
+
+    let mut updated = Vec::new();
+
+    for (path, insertions) in edits {
+        let mut contents = read(path)?;
+
+        for (prefix, marker, lines) in insertions {
+            contents = insert_day(&contents, (prefix, marker), day, &lines)
+                .ok_or_else(|| Error::UnexpectedManifest(path.clone()))?;
+        }
+
+        if path == &year_manifest {
+            contents = add_default_feature(&contents, &name)
+                .ok_or_else(|| Error::UnexpectedManifest(path.clone()))?;
+        }
+
+        updated.push((path.clone(), contents));
+    }
+
+    let render = |template: &str| {
+        template
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day_number}}", &day.to_string())
+            .replace("{{day}}", &format!("{day:02}"))
+    };
+
+    let created = [
+        (crate_dir.join("Cargo.toml"), render(CARGO_TEMPLATE)),
+        (crate_dir.join("src/lib.rs"), render(LIB_TEMPLATE)),
+        (crate_dir.join("src/error.rs"), render(ERROR_TEMPLATE)),
+        (
+            crate_dir.join("data").join(EXAMPLES_FILE_NAME),
+            render(EXAMPLES_TEMPLATE),
+        ),
+    ];
+
+    for (path, contents) in created.iter().chain(&updated) {
+        write(path, contents)?;
+    }
+
+    Ok(created.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Inserts lines next to the existing lines for other days, which start with `prefix` followed by
+/// the day and contain `marker`, keeping the days in order and taking over their indentation.
+/// Only the last inserted line takes the place of such a line, so lines such as attributes can be
+/// inserted along with it.
+fn insert_day(
+    contents: &str,
+    (prefix, marker): (&str, &str),
+    day: u8,
+    lines: &str,
+) -> Option<String> {
+    let mut result = contents.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let matching = result
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let trimmed = line.trim_start();
+            let other = trimmed.strip_prefix(prefix)?.get(..2)?.parse::<u8>().ok()?;
+
+            line.contains(marker)
+                .then_some((index, other, line.len() - trimmed.len()))
+        })
+        .collect::<Vec<_>>();
+
+    let &(_, _, indent) = matching.first()?;
+    let position = match matching.iter().rev().find(|&&(_, other, _)| other < day) {
+        Some(&(index, _, _)) => index + 1,
+        None => {
+            let (first, _, _) = matching[0];
+            let attributes = lines.lines().count() - 1;
+            first - attributes
+        }
+    };
+
+    let indented = lines
+        .lines()
+        .map(|line| format!("{}{line}", " ".repeat(indent)));
+
+    result.splice(position..position, indented);
+
+    Some(result.join("\n") + "\n")
+}
+
+fn add_default_feature(contents: &str, name: &str) -> Option<String> {
+    let start = contents.find("default = [")?;
+    let end = start + contents[start..].find(']')?;
+    let mut features = contents[start + "default = [".len()..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|feature| !feature.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    features.push(format!("\"{name}\""));
+    features.sort();
+
+    Some(format!(
+        "{}default = [{}]{}",
+        &contents[..start],
+        features.join(", "),
+        &contents[end + 1..]
+    ))
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::ReadManifest {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    let write_error = |source| Error::WriteScaffold {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(write_error)?;
+    }
+
+    fs::write(path, contents).map_err(write_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE_MANIFEST: &str = r#"[workspace]
+members = [
+    "aoc2025",
+    "day05",
+    "day06",
+]
+
+[workspace.dependencies]
+day05 = { path = "day05" }
+day06 = { path = "day06" }
+num = "0.4.3"
+"#;
+
+    const YEAR_MANIFEST: &str = r#"[features]
+default = ["day05", "day06"]
+day05 = ["dep:day05"]
+day06 = ["dep:day06"]
+
+[dependencies]
+day05 = { workspace = true, optional = true }
+day06 = { workspace = true, optional = true }
+"#;
+
+    const YEAR_LIB: &str = r#"#[cfg(feature = "day05")]
+pub use day05;
+#[cfg(feature = "day06")]
+pub use day06;
+
+pub fn register(registry: &mut Registry) {
+    #[cfg(feature = "day05")]
+    registry.register::<day05::Day05>();
+    #[cfg(feature = "day06")]
+    registry.register::<day06::Day06>();
+}
+
+fn it_registers_every_enabled_day() {
+    let enabled = [
+        cfg!(feature = "day05"),
+        cfg!(feature = "day06"),
+    ];
+}
+"#;
+
+    #[test]
+    fn it_scaffolds_days_and_refuses_to_overwrite_them() {
+        let directory = tempfile::tempdir().unwrap();
+        let workspace = directory.path();
+
+        for (file, contents) in [
+            ("Cargo.toml", WORKSPACE_MANIFEST),
+            ("aoc2025/Cargo.toml", YEAR_MANIFEST),
+            ("aoc2025/src/lib.rs", YEAR_LIB),
+        ] {
+            write(&workspace.join(file), contents).unwrap();
+        }
+
+        let created = scaffold(workspace, 2025, 7).unwrap();
+        let read = |file: &str| fs::read_to_string(workspace.join(file)).unwrap();
+
+        assert_eq!(created.len(), 4);
+        assert!(read("day07/src/lib.rs").contains("impl Solution for Day07"));
+        assert!(read("day07/src/lib.rs").contains("const DAY: u8 = 7;"));
+        assert!(read("Cargo.toml").contains("    \"day06\",\n    \"day07\",\n]"));
+        assert!(read("Cargo.toml").contains("day07 = { path = \"day07\" }\nnum"));
+        assert!(read("aoc2025/Cargo.toml").contains("\"day06\", \"day07\"]"));
+        assert!(read("aoc2025/Cargo.toml").contains("day07 = [\"dep:day07\"]\n\n"));
+        assert!(
+            read("aoc2025/Cargo.toml").ends_with("day07 = { workspace = true, optional = true }\n")
+        );
+        assert!(read("aoc2025/src/lib.rs").contains(
+            "    #[cfg(feature = \"day07\")]\n    registry.register::<day07::Day07>();\n}"
+        ));
+        assert!(read("aoc2025/src/lib.rs").contains("#[cfg(feature = \"day07\")]\npub use day07;"));
+        assert!(read("aoc2025/src/lib.rs").contains("cfg!(feature = \"day07\"),\n    ];"));
+
+        let manifest = read("Cargo.toml");
+        assert!(matches!(
+            scaffold(workspace, 2025, 7),
+            Err(Error::DayExists { day: 7, .. })
+        ));
+        assert_eq!(read("Cargo.toml"), manifest);
+        assert!(matches!(
+            scaffold(workspace, 2024, 8),
+            Err(Error::UnknownYear(2024))
+        ));
+    }
+
+    #[test]
+    fn it_keeps_days_in_order() {
+        let contents = "members = [\n    \"day02\",\n    \"day05\",\n]\n";
+
+        assert_eq!(
+            insert_day(contents, ("\"day", ""), 3, "\"day03\","),
+            Some("members = [\n    \"day02\",\n    \"day03\",\n    \"day05\",\n]\n".to_string())
+        );
+        assert_eq!(
+            insert_day(contents, ("\"day", ""), 1, "\"day01\","),
+            Some("members = [\n    \"day01\",\n    \"day02\",\n    \"day05\",\n]\n".to_string())
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use crate::error::Error;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{fs, path::Path};
+
+/// Reads a TOML state file, treating a file that does not exist yet as empty state.
+pub fn read<T: DeserializeOwned + Default>(path: &Path) -> Result<T, Error> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let contents = fs::read_to_string(path).map_err(|source| Error::ReadState {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    toml::from_str(&contents).map_err(|source| Error::InvalidState {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn write<T: Serialize>(path: &Path, state: &T) -> Result<(), Error> {
+    let contents = toml::to_string(state).expect("state is serializable to TOML");
+    let write_error = |source| Error::WriteState {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(write_error)?;
+    }
+
+    fs::write(path, contents).map_err(write_error)
+}
+
+/// Parses a name given on the command line that files are named after, rejecting names that
+/// would point outside of the directory the files are kept in.
+pub fn file_name(name: &str) -> Result<String, Error> {
+    if name.is_empty() || matches!(name, "." | "..") || name.contains(['/', '\\']) {
+        return Err(Error::InvalidName(name.to_string()));
+    }
+
+    Ok(name.to_string())
+}
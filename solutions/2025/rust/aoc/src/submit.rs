@@ -0,0 +1,351 @@
+use crate::{
+    calendar,
+    client::Client,
+    cooldown::Cooldown,
+    error::Error,
+    guesses::GuessHistory,
+    html::{self, between},
+    run,
+};
+use aoc_common::{InputProvider, Registry};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    /// Year to submit for, defaults to the most recent registered year.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Day to submit for.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part to submit the answer of.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Submit this answer instead of running the solution on the actual input.
+    #[arg(short, long)]
+    answer: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    AlreadySolved,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Correct => "that's the right answer",
+            Self::TooHigh => "that's not the right answer, it is too high",
+            Self::TooLow => "that's not the right answer, it is too low",
+            Self::Wrong => "that's not the right answer",
+            Self::RateLimited => "an answer was submitted too recently",
+            Self::AlreadySolved => "this part was already solved",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    /// How long the website wants us to wait before submitting another answer.
+    pub wait: Option<Duration>,
+}
+
+pub fn submit(
+    args: &SubmitArgs,
+    registry: &Registry,
+    client: &Client,
+    inputs: &InputProvider,
+    state_dir: &Path,
+) -> Result<bool, Error> {
+    let year = args
+        .year
+        .or(registry.latest_year())
+        .ok_or(Error::NoSolutions)?;
+
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let solution = registry.get(year, args.day).ok_or(Error::UnknownDay {
+                year,
+                day: args.day,
+            })?;
+
+            run::solve(solution, &inputs.load(year, args.day)?, args.part)?.to_string()
+        }
+    };
+
+    let outcome = submit_answer(
+        client,
+        &Cooldown::new(state_dir),
+        &mut GuessHistory::load(state_dir, year, args.day)?,
+        (year, args.day, args.part),
+        &answer,
+        SystemTime::now(),
+    )?;
+
+    print!(
+        "day {:02} part {}: submitted {answer}, {}",
+        args.day, args.part, outcome.verdict
+    );
+
+    match outcome.wait {
+        Some(wait) => println!(
+            " (wait {} before submitting again)",
+            calendar::format_duration(wait)
+        ),
+        None => println!(),
+    }
+
+    Ok(matches!(
+        outcome.verdict,
+        Verdict::Correct | Verdict::AlreadySolved
+    ))
+}
+
+/// Posts an answer and classifies the response, refusing to do so while a previous submission's
+/// cooldown is still running or when earlier guesses already rule the answer out.
+pub fn submit_answer(
+    client: &Client,
+    cooldown: &Cooldown,
+    history: &mut GuessHistory,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+    now: SystemTime,
+) -> Result<Outcome, Error> {
+    history.check(part, answer)?;
+
+    if let Some(remaining) = cooldown.remaining(now)? {
+        return Err(Error::CoolingDown {
+            remaining: calendar::format_duration(remaining),
+        });
+    }
+
+    let page = client.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+
+    let outcome = classify(&page)?;
+
+    if let Some(wait) = outcome.wait {
+        cooldown.start(now, wait)?;
+    }
+
+    history.record(part, answer, outcome.verdict)?;
+
+    Ok(outcome)
+}
+
+pub fn classify(page: &str) -> Result<Outcome, Error> {
+    let text = main_text(page);
+    let lowercase = text.to_lowercase();
+
+    let verdict = if lowercase.contains("that's the right answer") {
+        Verdict::Correct
+    } else if lowercase.contains("answer too recently") {
+        Verdict::RateLimited
+    } else if lowercase.contains("don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if lowercase.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if lowercase.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if lowercase.contains("that's not the right answer") {
+        Verdict::Wrong
+    } else {
+        return Err(Error::UnexpectedResponse(text));
+    };
+
+    let wait = between(&lowercase, "you have ", " left to wait")
+        .or_else(|| between(&lowercase, "please wait ", " before trying again"))
+        .and_then(parse_wait);
+
+    Ok(Outcome { verdict, wait })
+}
+
+/// The text of the `<article>` the website puts its response in, with markup removed.
+fn main_text(page: &str) -> String {
+    let article = between(page, "<article>", "</article>").unwrap_or(page);
+
+    html::text(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses waits such as `34s`, `1m 5s`, `one minute` and `5 minutes`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    let mut amount = None;
+
+    for token in text.split_whitespace() {
+        let digits = token.len()
+            - token
+                .trim_start_matches(|ch: char| ch.is_ascii_digit())
+                .len();
+        let (number, unit) = token.split_at(digits);
+
+        if let Ok(number) = number.parse::<u64>() {
+            amount = Some(number);
+        } else if matches!(unit, "a" | "an" | "one") {
+            amount = Some(1);
+            continue;
+        }
+
+        if unit.is_empty() {
+            continue;
+        }
+
+        let multiplier = match unit.trim_end_matches(['s', ',', '.']) {
+            "h" | "hour" => 3600,
+            "m" | "minute" => 60,
+            "" | "second" => 1,
+            _ => return None,
+        };
+
+        seconds += amount.take()? * multiplier;
+    }
+
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn it_classifies_responses() {
+        let cases = [
+            (
+                "That's the right answer!  You are <em>one gold star</em> closer.",
+                Verdict::Correct,
+                None,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+                Verdict::TooHigh,
+                Some(60),
+            ),
+            (
+                "That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.",
+                Verdict::TooLow,
+                Some(300),
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Verdict::Wrong,
+                None,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+                Verdict::RateLimited,
+                Some(65),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+                None,
+            ),
+        ];
+
+        for (message, verdict, wait) in cases {
+            let outcome = classify(&page(message)).unwrap();
+
+            assert_eq!(outcome.verdict, verdict, "{message}");
+            assert_eq!(outcome.wait, wait.map(Duration::from_secs), "{message}");
+        }
+    }
+
+    #[test]
+    fn it_rejects_unrecognised_responses() {
+        assert!(matches!(
+            classify(&page("Puzzle inputs differ by user.")),
+            Err(Error::UnexpectedResponse(text)) if text == "Puzzle inputs differ by user."
+        ));
+    }
+
+    #[test]
+    fn it_posts_answers_and_enforces_the_cooldown() {
+        let server = StubServer::start(vec![(
+            200,
+            page(
+                "That's not the right answer; your answer is too low.  You have 30s left to wait.",
+            ),
+        )]);
+
+        let state_dir = tempfile::tempdir().unwrap();
+        let cooldown = Cooldown::new(state_dir.path());
+        let mut history = GuessHistory::load(state_dir.path(), 2025, 4).unwrap();
+        let client = Client::new(server.url(), Some("cookie".to_string()));
+        let now = SystemTime::now();
+
+        let outcome =
+            submit_answer(&client, &cooldown, &mut history, (2025, 4, 2), "1234", now).unwrap();
+        let requests = server.requests();
+
+        assert_eq!(outcome.verdict, Verdict::TooLow);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/4/answer");
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+
+        let during_cooldown = now + Duration::from_secs(10);
+        let result = submit_answer(
+            &client,
+            &cooldown,
+            &mut history,
+            (2025, 4, 2),
+            "1235",
+            during_cooldown,
+        );
+
+        assert!(matches!(result, Err(Error::CoolingDown { .. })));
+        assert_eq!(server.requests().len(), 1);
+
+        let after_cooldown = now + Duration::from_secs(31);
+        assert_eq!(cooldown.remaining(after_cooldown).unwrap(), None);
+    }
+
+    #[test]
+    fn it_refuses_answers_ruled_out_by_earlier_guesses() {
+        let server = StubServer::start(vec![
+            (
+                200,
+                page("That's not the right answer; your answer is too high."),
+            ),
+            (200, page("That's the right answer!")),
+        ]);
+
+        let state_dir = tempfile::tempdir().unwrap();
+        let cooldown = Cooldown::new(state_dir.path());
+        let mut history = GuessHistory::load(state_dir.path(), 2025, 5).unwrap();
+        let client = Client::new(server.url(), Some("cookie".to_string()));
+        let now = SystemTime::now();
+        let mut submit =
+            |answer| submit_answer(&client, &cooldown, &mut history, (2025, 5, 1), answer, now);
+
+        assert_eq!(submit("900").unwrap().verdict, Verdict::TooHigh);
+        assert!(matches!(submit("900"), Err(Error::KnownWrongAnswer { .. })));
+        assert!(matches!(submit("901"), Err(Error::OutOfBounds { .. })));
+        assert_eq!(submit("450").unwrap().verdict, Verdict::Correct);
+        assert_eq!(server.requests().len(), 2);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{error::Error, run};
+use aoc_common::{
+    Answer, ExpectedAnswer, InputProvider, KnownAnswers, Registry, answers::input_hash,
+    encryption::InputKey, solution::DynSolution,
+};
+use clap::Args;
+use std::path::Path;
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Only verify days of this year instead of every registered day.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Matches,
+    Regressed {
+        expected: ExpectedAnswer,
+    },
+    Unknown,
+    /// The known answer is hashed, but there is no key to compare the answer with it.
+    Unverifiable,
+}
+
+/// The answer a part produced and how it compares to the known answer.
+pub type Verification = Result<(Answer, Status), Error>;
+
+#[derive(Debug, Default)]
+struct Summary {
+    matching: usize,
+    regressed: usize,
+    unknown: usize,
+    failed: usize,
+}
+
+/// Re-runs every registered day on its actual input and compares the answers against the known
+/// answers, returning whether none of them regressed or failed.
+pub fn verify(
+    args: &VerifyArgs,
+    registry: &Registry,
+    inputs: &InputProvider,
+    answers: &KnownAnswers,
+) -> Result<bool, Error> {
+    let mut summary = Summary::default();
+
+    let solutions = registry
+        .solutions()
+        .filter(|solution| args.year.is_none_or(|year| solution.year() == year));
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+
+        let input = match inputs.load(year, day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{year} day {day:02}: error: {error}");
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for (part, result) in verify_day(solution, &input, answers) {
+            let prefix = format!("{year} day {day:02} part {part}");
+
+            match result {
+                Ok((answer, Status::Matches)) => {
+                    println!("{prefix}: ok ({answer})");
+                    summary.matching += 1;
+                }
+                Ok((answer, Status::Regressed { expected })) => {
+                    match expected {
+                        ExpectedAnswer::Plain(expected) => {
+                            println!("{prefix}: regressed, expected {expected} but got {answer}")
+                        }
+                        ExpectedAnswer::Hashed { .. } => {
+                            println!(
+                                "{prefix}: regressed, {answer} does not match the known answer"
+                            )
+                        }
+                    }
+                    summary.regressed += 1;
+                }
+                Ok((answer, Status::Unknown)) => {
+                    println!(
+                        "{prefix}: unknown ({answer}), input hash {}",
+                        input_hash(&input)
+                    );
+                    summary.unknown += 1;
+                }
+                Ok((answer, Status::Unverifiable)) => {
+                    println!("{prefix}: unverifiable ({answer}), no key to compare its hash");
+                    summary.unknown += 1;
+                }
+                Err(error) => {
+                    eprintln!("{prefix}: error: {error}");
+                    summary.failed += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} matching, {} regressed, {} unknown, {} failed",
+        summary.matching, summary.regressed, summary.unknown, summary.failed
+    );
+
+    Ok(summary.regressed == 0 && summary.failed == 0)
+}
+
+/// Replaces the plain answers in the answers file with salted hashes keyed with the key in
+/// `key_file`.
+pub fn hash_answers(path: &Path, key_file: &Path) -> Result<bool, Error> {
+    if !key_file.exists() {
+        return Err(Error::MissingAnswerKey(key_file.to_path_buf()));
+    }
+
+    let key = InputKey::from_file(key_file)?;
+    let mut answers = KnownAnswers::from_file(path)?;
+    let hashed = answers.hash_answers(&key);
+
+    answers.save(path)?;
+    println!("hashed {hashed} answers in '{}'", path.display());
+
+    Ok(true)
+}
+
+pub fn verify_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    answers: &KnownAnswers,
+) -> Vec<(u8, Verification)> {
+    (1..=2)
+        .filter_map(|part| {
+            let result = match run::solve(solution, input, part) {
+                Err(Error::MissingPart2 { .. }) => return None,
+                result => result.map(|answer| {
+                    let status = status(solution, part, input, &answer, answers);
+                    (answer, status)
+                }),
+            };
+
+            Some((part, result))
+        })
+        .collect()
+}
+
+/// Compares the answer a part produced for an input against its known answer.
+pub fn status(
+    solution: &dyn DynSolution,
+    part: u8,
+    input: &str,
+    answer: &Answer,
+    answers: &KnownAnswers,
+) -> Status {
+    let known = answers.get(solution.year(), solution.day(), part, input);
+
+    let Some(expected) = known.map(|known| &known.answer) else {
+        return Status::Unknown;
+    };
+
+    match expected.matches(answer, answers.key()) {
+        Some(true) => Status::Matches,
+        Some(false) => Status::Regressed {
+            expected: expected.clone(),
+        },
+        None => Status::Unverifiable,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_compares_answers_against_known_answers() {
+        let registry = aoc2025::registry();
+        let solution = registry.get(2025, 4).unwrap();
+        let input = include_str!("../../day04/data/example-input.txt");
+        let answers = toml::from_str::<KnownAnswers>(
+            r#"
+            [[answer]]
+            year = 2025
+            day = 4
+            part = 1
+            answer = "13"
+
+            [[answer]]
+            year = 2025
+            day = 4
+            part = 2
+            answer = "42"
+            "#,
+        )
+        .unwrap();
+
+        let results = verify_day(solution, input, &answers)
+            .into_iter()
+            .map(|(part, result)| (part, result.unwrap()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            results,
+            [
+                (1, (Answer::Number(13), Status::Matches)),
+                (
+                    2,
+                    (
+                        Answer::Number(43),
+                        Status::Regressed {
+                            expected: ExpectedAnswer::Plain(Answer::Number(42))
+                        }
+                    )
+                ),
+            ]
+        );
+
+        let unknown = verify_day(solution, input, &KnownAnswers::default());
+        assert!(matches!(unknown[0], (1, Ok((_, Status::Unknown)))));
+    }
+}
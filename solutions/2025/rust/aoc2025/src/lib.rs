@@ -0,0 +1,69 @@
+//! Every 2025 day behind a cargo feature of the same name, all of which are enabled by default.
+
+use aoc_common::Registry;
+
+#[cfg(feature = "day01")]
+pub use day01;
+#[cfg(feature = "day02")]
+pub use day02;
+#[cfg(feature = "day03")]
+pub use day03;
+#[cfg(feature = "day04")]
+pub use day04;
+#[cfg(feature = "day05")]
+pub use day05;
+#[cfg(feature = "day06")]
+pub use day06;
+
+/// Registers every day that is enabled through its feature.
+#[allow(unused_variables, reason = "unused when every day is disabled")]
+pub fn register(registry: &mut Registry) {
+    #[cfg(feature = "day01")]
+    registry.register::<day01::Day01>();
+    #[cfg(feature = "day02")]
+    registry.register::<day02::Day02>();
+    #[cfg(feature = "day03")]
+    registry.register::<day03::Day03>();
+    #[cfg(feature = "day04")]
+    registry.register::<day04::Day04>();
+    #[cfg(feature = "day05")]
+    registry.register::<day05::Day05>();
+    #[cfg(feature = "day06")]
+    registry.register::<day06::Day06>();
+}
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    register(&mut registry);
+
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_registers_every_enabled_day() {
+        let enabled = [
+            cfg!(feature = "day01"),
+            cfg!(feature = "day02"),
+            cfg!(feature = "day03"),
+            cfg!(feature = "day04"),
+            cfg!(feature = "day05"),
+            cfg!(feature = "day06"),
+        ];
+
+        let expected = (1..)
+            .zip(enabled)
+            .filter_map(|(day, enabled)| enabled.then_some(day))
+            .collect::<Vec<u8>>();
+
+        let days = registry()
+            .year(2025)
+            .map(|solution| solution.day())
+            .collect::<Vec<_>>();
+
+        assert_eq!(days, expected);
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Global allocator that counts the allocations of the current thread, to be installed with
+/// `#[global_allocator]` by a binary that wants to report them through [`measure`]. Counting per
+/// thread keeps the numbers of days that run in parallel apart.
+pub struct CountingAllocator;
+
+/// The allocations made while running a closure, with `peak` being the most bytes that were live
+/// at once on top of what was live before.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+/// Records an allocation of `allocated` bytes that changed the live bytes by `change`.
+fn record(allocated: usize, change: i64) {
+    // Ignore allocations made while the thread local is being torn down.
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+
+        if allocated > 0 {
+            current.count += 1;
+            current.bytes += allocated as u64;
+        }
+
+        current.live += change;
+        current.peak = current.peak.max(current.live);
+        counters.set(current);
+    });
+}
+
+// SAFETY: Every call is forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+
+        if !pointer.is_null() {
+            record(layout.size(), layout.size() as i64);
+        }
+
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc_zeroed(layout) };
+
+        if !pointer.is_null() {
+            record(layout.size(), layout.size() as i64);
+        }
+
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        record(0, -(layout.size() as i64));
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = unsafe { System.realloc(pointer, layout, new_size) };
+
+        if !new_pointer.is_null() {
+            record(new_size, new_size as i64 - layout.size() as i64);
+        }
+
+        new_pointer
+    }
+}
+
+/// Runs a closure and returns the allocations it made on the current thread. Everything is zero
+/// unless [`CountingAllocator`] is the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let before = COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.peak = current.live;
+        counters.set(current);
+        current
+    });
+
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+
+    let allocations = Allocations {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live).max(0) as u64,
+    };
+
+    (result, allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn it_counts_allocations_bytes_and_peak() {
+        let (_, allocations) = measure(|| {
+            let first = black_box(vec![0u8; 1000]);
+            drop(first);
+            black_box(vec![0u8; 600]).len()
+        });
+
+        assert_eq!(allocations.count, 2);
+        assert_eq!(allocations.bytes, 1600);
+        assert_eq!(allocations.peak, 1000);
+
+        let (_, allocations) = measure(|| black_box(1 + 1));
+        assert_eq!(allocations, Allocations::default());
+    }
+
+    #[test]
+    fn it_counts_reallocations() {
+        let (_, allocations) = measure(|| {
+            let mut numbers = Vec::<u64>::with_capacity(1);
+            numbers.push(1);
+            numbers.reserve_exact(3);
+            black_box(numbers)
+        });
+
+        assert_eq!(allocations.count, 2);
+        assert_eq!(allocations.bytes, 8 + 32);
+        assert_eq!(allocations.peak, 32);
+    }
+}
@@ -0,0 +1,391 @@
+use crate::{config::Config, encryption::InputKey, error::AnswersError, solution::Answer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    fs,
+    hash::{BuildHasher, Hasher, RandomState},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+const HASH_PREFIX: &str = "hmac-sha256:";
+
+/// A known answer, kept either as plain text or as a salted hash so the answers file can be
+/// published without spoiling the puzzles. Hashed answers are written as
+/// `hmac-sha256:<salt>:<hash>`, keyed with the [`InputKey`] so they cannot be brute-forced by
+/// anyone who does not hold the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpectedAnswer {
+    Plain(Answer),
+    Hashed { salt: String, hash: String },
+}
+
+impl ExpectedAnswer {
+    /// Hashes an answer with a freshly generated salt.
+    pub fn hashed(answer: &Answer, key: &InputKey) -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos(),
+        );
+
+        let salt = format!("{:016x}", hasher.finish());
+        let hash = answer_hash(key, &salt, answer);
+
+        Self::Hashed { salt, hash }
+    }
+
+    /// Compares an answer against this one, returning `None` when it is hashed and there is no
+    /// key to hash the answer with.
+    pub fn matches(&self, answer: &Answer, key: Option<&InputKey>) -> Option<bool> {
+        match self {
+            Self::Plain(expected) => Some(expected == answer),
+            Self::Hashed { salt, hash } => Some(answer_hash(key?, salt, answer) == *hash),
+        }
+    }
+}
+
+impl Display for ExpectedAnswer {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain(answer) => write!(formatter, "{answer}"),
+            Self::Hashed { salt, hash } => write!(formatter, "{HASH_PREFIX}{salt}:{hash}"),
+        }
+    }
+}
+
+impl FromStr for ExpectedAnswer {
+    type Err = Infallible;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let hashed = string
+            .strip_prefix(HASH_PREFIX)
+            .and_then(|hashed| hashed.split_once(':'));
+
+        Ok(match hashed {
+            Some((salt, hash)) => Self::Hashed {
+                salt: salt.to_string(),
+                hash: hash.to_string(),
+            },
+            None => Self::Plain(string.parse()?),
+        })
+    }
+}
+
+/// Plain answers are written like [`Answer`], hashed ones as strings.
+impl Serialize for ExpectedAnswer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Plain(answer) => answer.serialize(serializer),
+            Self::Hashed { .. } => serializer.collect_str(self),
+        }
+    }
+}
+
+/// Accepts integers as well as strings, which are parsed like [`ExpectedAnswer::from_str`].
+impl<'de> Deserialize<'de> for ExpectedAnswer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(i64),
+            Text(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Number(number) => Self::Plain(number.into()),
+            Raw::Text(text) => text.parse().unwrap_or_else(|error| match error {}),
+        })
+    }
+}
+
+/// An answer that was verified on the website, optionally tied to the input it was computed from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnownAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: ExpectedAnswer,
+    /// Hash of the input as produced by [`input_hash`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+}
+
+/// The verified answers in `answers.toml`, or in the file configured as `answers-file`, together
+/// with the key their hashes were computed with, if it is available.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnownAnswers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<KnownAnswer>,
+    #[serde(skip)]
+    key: Option<InputKey>,
+}
+
+impl KnownAnswers {
+    pub fn path(config: &Config) -> PathBuf {
+        config
+            .answers_file
+            .clone()
+            .unwrap_or_else(|| config.workspace.dir().join(ANSWERS_FILE_NAME))
+    }
+
+    /// Loads the known answers along with the key in the configured key file, if there is one.
+    pub fn load(config: &Config) -> Result<Self, AnswersError> {
+        let path = Self::path(config);
+        let key_file = config.key_file();
+
+        let answers = if path.exists() {
+            Self::from_file(&path)?
+        } else {
+            Self::default()
+        };
+
+        Ok(if key_file.exists() {
+            answers.with_key(InputKey::from_file(&key_file)?)
+        } else {
+            answers
+        })
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, AnswersError> {
+        let contents = fs::read_to_string(path).map_err(|source| AnswersError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        toml::from_str(&contents).map_err(|source| AnswersError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn with_key(mut self, key: InputKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub fn key(&self) -> Option<&InputKey> {
+        self.key.as_ref()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let contents = toml::to_string(self).expect("answers are serializable to TOML");
+
+        fs::write(path, contents).map_err(|source| AnswersError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn answers(&self) -> impl Iterator<Item = &KnownAnswer> {
+        self.answers.iter()
+    }
+
+    /// Replaces every plain answer with a salted hash keyed with `key`, returning how many answers
+    /// were hashed.
+    pub fn hash_answers(&mut self, key: &InputKey) -> usize {
+        let mut hashed = 0;
+
+        for known in &mut self.answers {
+            if let ExpectedAnswer::Plain(answer) = &known.answer {
+                known.answer = ExpectedAnswer::hashed(answer, key);
+                hashed += 1;
+            }
+        }
+
+        hashed
+    }
+
+    /// Looks up the answer to a part, preferring one recorded for this exact input over one that
+    /// is not tied to any input.
+    pub fn get(&self, year: u16, day: u8, part: u8, input: &str) -> Option<&KnownAnswer> {
+        let candidates = self
+            .answers
+            .iter()
+            .filter(|known| (known.year, known.day, known.part) == (year, day, part));
+
+        let hash = input_hash(input);
+        let mut fallback = None;
+
+        for known in candidates {
+            match &known.input {
+                Some(input) if *input == hash => return Some(known),
+                Some(_) => {}
+                None => fallback = fallback.or(Some(known)),
+            }
+        }
+
+        fallback
+    }
+}
+
+/// Hashes an input, ignoring trailing whitespace so that a missing final newline does not matter.
+pub fn input_hash(input: &str) -> String {
+    sha256_hex(input.trim_end())
+}
+
+fn answer_hash(key: &InputKey, salt: &str, answer: &Answer) -> String {
+    key.authenticate(format!("{salt}:{answer}").as_bytes())
+}
+
+fn sha256_hex(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(contents: &str) -> KnownAnswers {
+        toml::from_str(contents).unwrap()
+    }
+
+    #[test]
+    fn it_looks_up_answers_by_year_day_and_part() {
+        let answers = answers(
+            r#"
+            [[answer]]
+            year = 2025
+            day = 1
+            part = 1
+            answer = "123"
+
+            [[answer]]
+            year = 2025
+            day = 1
+            part = 2
+            answer = "456"
+            "#,
+        );
+
+        let answer = |part| {
+            answers
+                .get(2025, 1, part, "L68")
+                .map(|known| known.answer.to_string())
+        };
+
+        assert_eq!(answer(1).as_deref(), Some("123"));
+        assert_eq!(answer(2).as_deref(), Some("456"));
+        assert_eq!(answers.get(2025, 2, 1, "L68"), None);
+    }
+
+    #[test]
+    fn it_accepts_answers_written_as_integers() {
+        let answers = answers(
+            r#"
+            [[answer]]
+            year = 2025
+            day = 4
+            part = 1
+            answer = 13
+
+            [[answer]]
+            year = 2025
+            day = 4
+            part = 2
+            answer = "43"
+            "#,
+        );
+
+        let answer = |part| {
+            answers
+                .get(2025, 4, part, "@@.")
+                .map(|known| known.answer.clone())
+        };
+
+        assert_eq!(answer(1), Some(ExpectedAnswer::Plain(Answer::Number(13))));
+        assert_eq!(answer(2), Some(ExpectedAnswer::Plain(Answer::Number(43))));
+        assert!(toml::to_string(&answers).unwrap().contains("answer = 13\n"));
+    }
+
+    #[test]
+    fn it_prefers_answers_for_the_same_input() {
+        let answers = answers(&format!(
+            r#"
+            [[answer]]
+            year = 2025
+            day = 4
+            part = 1
+            answer = "1"
+
+            [[answer]]
+            year = 2025
+            day = 4
+            part = 1
+            answer = "2"
+            input = "{}"
+
+            [[answer]]
+            year = 2025
+            day = 4
+            part = 1
+            answer = "3"
+            input = "{}"
+            "#,
+            input_hash("@@.\n"),
+            input_hash("..."),
+        ));
+
+        let answer = |input| {
+            answers
+                .get(2025, 4, 1, input)
+                .map(|known| known.answer.to_string())
+        };
+
+        assert_eq!(answer("@@.").as_deref(), Some("2"));
+        assert_eq!(answer("...").as_deref(), Some("3"));
+        assert_eq!(answer(".@.").as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn it_matches_hashed_answers_without_storing_them() {
+        let mut answers = answers(
+            r#"
+            [[answer]]
+            year = 2025
+            day = 5
+            part = 1
+            answer = "4242"
+            "#,
+        );
+
+        let key = InputKey::generate();
+
+        assert_eq!(answers.hash_answers(&key), 1);
+        assert_eq!(answers.hash_answers(&key), 0);
+
+        let contents = toml::to_string(&answers).unwrap();
+        assert!(!contents.contains("\"4242\""));
+
+        let expected = toml::from_str::<KnownAnswers>(&contents).unwrap().answers[0]
+            .answer
+            .clone();
+
+        assert!(matches!(expected, ExpectedAnswer::Hashed { .. }));
+        assert_eq!(
+            expected.matches(&Answer::Number(4242), Some(&key)),
+            Some(true)
+        );
+        assert_eq!(
+            expected.matches(&Answer::Number(4243), Some(&key)),
+            Some(false)
+        );
+        assert_eq!(
+            expected.matches(&Answer::Number(4242), Some(&InputKey::generate())),
+            Some(false)
+        );
+        assert_eq!(expected.matches(&Answer::Number(4242), None), None);
+    }
+}
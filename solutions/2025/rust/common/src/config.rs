@@ -0,0 +1,114 @@
+use crate::{encryption::KEY_FILE_NAME, error::ConfigError, paths::Workspace};
+use serde::Deserialize;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+/// Settings read from `aoc.toml` in the workspace, or from the file named by `AOC_CONFIG`.
+/// Relative paths are resolved against the directory that contains the config file, and paths
+/// that are not set default to locations in the workspace the config was loaded for.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub session_file: Option<PathBuf>,
+    pub base_url: Option<String>,
+    pub state_dir: Option<PathBuf>,
+    pub answers_file: Option<PathBuf>,
+    pub key_file: Option<PathBuf>,
+    pub puzzle_dir: Option<PathBuf>,
+    #[serde(skip)]
+    pub workspace: Workspace,
+}
+
+impl Config {
+    pub fn load(workspace: Workspace) -> Result<Self, ConfigError> {
+        let config = match env::var_os(CONFIG_ENV) {
+            Some(path) => Self::from_file(Path::new(&path))?,
+            None => {
+                let path = workspace.dir().join(CONFIG_FILE_NAME);
+
+                if path.exists() {
+                    Self::from_file(&path)?
+                } else {
+                    Self::default()
+                }
+            }
+        };
+
+        Ok(Self {
+            workspace,
+            ..config
+        })
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let contents = fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        Self::parse(&contents, base_dir).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Directory for state the tooling keeps between runs, `.aoc/` in the repository by default.
+    pub fn state_dir(&self) -> PathBuf {
+        self.state_dir
+            .clone()
+            .unwrap_or_else(|| self.workspace.repository_dir().join(".aoc"))
+    }
+
+    /// Key the inputs are encrypted with, `inputs.key` in the state directory by default.
+    pub fn key_file(&self) -> PathBuf {
+        self.key_file
+            .clone()
+            .unwrap_or_else(|| self.state_dir().join(KEY_FILE_NAME))
+    }
+
+    /// Directory puzzle descriptions are archived in, `puzzles/` in the repository by default.
+    pub fn puzzle_dir(&self) -> PathBuf {
+        self.puzzle_dir
+            .clone()
+            .unwrap_or_else(|| self.workspace.repository_dir().join("puzzles"))
+    }
+
+    pub fn parse(contents: &str, base_dir: &Path) -> Result<Self, toml::de::Error> {
+        let mut config = toml::from_str::<Self>(contents)?;
+        config.input_dir = config.input_dir.map(|dir| base_dir.join(dir));
+        config.session_file = config.session_file.map(|file| base_dir.join(file));
+        config.state_dir = config.state_dir.map(|dir| base_dir.join(dir));
+        config.answers_file = config.answers_file.map(|file| base_dir.join(file));
+        config.key_file = config.key_file.map(|file| base_dir.join(file));
+        config.puzzle_dir = config.puzzle_dir.map(|dir| base_dir.join(dir));
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_resolves_paths_against_the_config_directory() {
+        let config = Config::parse("input-dir = \"../inputs\"", Path::new("/aoc")).unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("/aoc/../inputs")));
+
+        let config = Config::parse("input-dir = \"/srv/inputs\"", Path::new("/aoc")).unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("/srv/inputs")));
+    }
+
+    #[test]
+    fn it_rejects_unknown_keys() {
+        assert!(Config::parse("inputs = \"x\"", Path::new(".")).is_err());
+        assert_eq!(Config::parse("", Path::new(".")), Ok(Config::default()));
+    }
+}
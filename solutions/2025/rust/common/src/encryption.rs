@@ -0,0 +1,147 @@
+use crate::error::EncryptionError;
+use chacha20poly1305::{
+    AeadCore, ChaCha20Poly1305, Key, KeyInit, Nonce,
+    aead::{Aead, OsRng},
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::{
+    fmt::{self, Debug},
+    fs,
+    path::Path,
+};
+
+pub const KEY_FILE_NAME: &str = "inputs.key";
+
+/// Extension of inputs that are stored encrypted, appended to the name of the plain input.
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+const NONCE_LEN: usize = 12;
+
+/// Symmetric key used to keep inputs encrypted at rest. Encrypted files hold a random nonce
+/// followed by the ChaCha20-Poly1305 ciphertext, and the key file holds the key as hex.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InputKey(Key);
+
+impl InputKey {
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, EncryptionError> {
+        let contents = fs::read_to_string(path).map_err(|source| EncryptionError::ReadKey {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::from_hex(contents.trim()).ok_or_else(|| EncryptionError::InvalidKey {
+            path: path.to_path_buf(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), EncryptionError> {
+        let write_error = |source| EncryptionError::WriteKey {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(write_error)?;
+        }
+
+        fs::write(path, format!("{}\n", to_hex(&self.0))).map_err(write_error)
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = ChaCha20Poly1305::new(&self.0)
+            .encrypt(&nonce, plaintext)
+            .expect("plaintext fits in a single message");
+
+        [nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// Decrypts data produced by [`InputKey::encrypt`], returning `None` when it was encrypted
+    /// with a different key or has been tampered with.
+    pub fn decrypt(&self, data: &[u8]) -> Option<Vec<u8>> {
+        if data.len() < NONCE_LEN {
+            return None;
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+        ChaCha20Poly1305::new(&self.0)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .ok()
+    }
+
+    /// Computes the HMAC-SHA256 of a message keyed with this key, as hex. Used to hash known
+    /// answers so that they cannot be recovered without the key.
+    pub fn authenticate(&self, message: &[u8]) -> String {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.0)
+            .expect("HMAC accepts keys of any length");
+        mac.update(message);
+
+        to_hex(&mac.finalize().into_bytes())
+    }
+
+    fn from_hex(hex: &str) -> Option<Self> {
+        if hex.len() != 2 * size_of::<Key>() || !hex.is_ascii() {
+            return None;
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self(*Key::from_slice(&bytes)))
+    }
+}
+
+impl Debug for InputKey {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("InputKey(..)")
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_inputs() {
+        let key = InputKey::generate();
+        let encrypted = key.encrypt(b"L68\nR48\n");
+
+        assert_ne!(&encrypted[NONCE_LEN..], b"L68\nR48\n");
+        assert_eq!(key.decrypt(&encrypted).as_deref(), Some(&b"L68\nR48\n"[..]));
+        assert_eq!(InputKey::generate().decrypt(&encrypted), None);
+        assert_eq!(key.decrypt(b"short"), None);
+    }
+
+    #[test]
+    fn it_saves_and_loads_keys() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("nested").join(KEY_FILE_NAME);
+        let key = InputKey::generate();
+
+        key.save(&path).unwrap();
+        let loaded = InputKey::from_file(&path).unwrap();
+
+        assert_eq!(
+            loaded.decrypt(&key.encrypt(b"3-5")).as_deref(),
+            Some(&b"3-5"[..])
+        );
+
+        fs::write(&path, "not a key").unwrap();
+        assert!(matches!(
+            InputKey::from_file(&path),
+            Err(EncryptionError::InvalidKey { .. })
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum GridError<E> {
+    #[error("grid cannot be parsed from jagged input")]
+    JaggedInput,
+    #[error(transparent)]
+    InvalidCell(E),
+}
+
+#[derive(Debug, Error)]
+pub enum WorkspaceError {
+    #[error(
+        "no workspace found in '{0}' or any directory above it, set AOC_WORKSPACE to point at one"
+    )]
+    NotFound(PathBuf),
+    #[error("'{0}' is not a workspace, it holds neither a workspace manifest nor a config file")]
+    NotAWorkspace(PathBuf),
+    #[error("failed to resolve the current directory: {0}")]
+    CurrentDir(io::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read config file '{path}': {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to parse config file '{path}': {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("no input found for day {day} of {year}, expected it at '{path}'")]
+    Missing { year: u16, day: u8, path: PathBuf },
+    #[error("failed to read input from '{path}': {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to read input from stdin: {0}")]
+    Stdin(io::Error),
+    #[error("input '{path}' is encrypted but there is no key at '{key_file}'")]
+    MissingKey { path: PathBuf, key_file: PathBuf },
+    #[error("failed to decrypt input '{path}', it was encrypted with a different key")]
+    Decrypt { path: PathBuf },
+    #[error("failed to write input '{path}': {source}")]
+    Write { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    Encryption(#[from] EncryptionError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error(transparent)]
+    Workspace(#[from] WorkspaceError),
+}
+
+#[derive(Debug, Error)]
+pub enum EncryptionError {
+    #[error("failed to read key file '{path}': {source}")]
+    ReadKey { path: PathBuf, source: io::Error },
+    #[error("key file '{path}' does not contain a valid key")]
+    InvalidKey { path: PathBuf },
+    #[error("failed to write key file '{path}': {source}")]
+    WriteKey { path: PathBuf, source: io::Error },
+}
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("failed to read answers file '{path}': {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to parse answers file '{path}': {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("failed to write answers file '{path}': {source}")]
+    Write { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    Encryption(#[from] EncryptionError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+#[derive(Debug, Error)]
+pub enum ExamplesError {
+    #[error("failed to read example '{path}': {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to parse examples manifest '{path}': {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("failed to write '{path}': {source}")]
+    Write { path: PathBuf, source: io::Error },
+}
@@ -0,0 +1,166 @@
+use crate::{error::ExamplesError, solution::Answer};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const EXAMPLES_FILE_NAME: &str = "examples.toml";
+
+/// An example from the puzzle description. A part without an expected answer does not apply to
+/// the example, which happens when a later part introduces an example of its own.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub name: String,
+    /// Path of the example input, relative to the manifest.
+    pub file: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+}
+
+/// The examples of a day, listed in `data/examples.toml` in the day's crate.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Examples {
+    #[serde(default, rename = "example")]
+    examples: Vec<Example>,
+    #[serde(skip)]
+    directory: PathBuf,
+}
+
+impl Examples {
+    /// Loads the manifest in `directory`, treating a missing manifest as a day without examples.
+    pub fn load(directory: &Path) -> Result<Self, ExamplesError> {
+        let path = directory.join(EXAMPLES_FILE_NAME);
+
+        if !path.exists() {
+            return Ok(Self {
+                directory: directory.to_path_buf(),
+                ..Self::default()
+            });
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|source| ExamplesError::Read {
+            path: path.clone(),
+            source,
+        })?;
+
+        let examples = toml::from_str::<Self>(&contents)
+            .map_err(|source| ExamplesError::Parse { path, source })?;
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            ..examples
+        })
+    }
+
+    pub fn examples(&self) -> impl Iterator<Item = &Example> {
+        self.examples.iter()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Example> {
+        self.examples.iter().find(|example| example.name == name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.examples.is_empty()
+    }
+
+    /// Adds an example, replacing the one with the same name if there is one.
+    pub fn insert(&mut self, example: Example) {
+        match self
+            .examples
+            .iter_mut()
+            .find(|existing| existing.name == example.name)
+        {
+            Some(existing) => *existing = example,
+            None => self.examples.push(example),
+        }
+    }
+
+    /// Writes the manifest back into the directory it was loaded from.
+    pub fn save(&self) -> Result<(), ExamplesError> {
+        let contents = toml::to_string(self).expect("examples are serializable to TOML");
+
+        self.write(EXAMPLES_FILE_NAME.as_ref(), &contents)
+    }
+
+    pub fn write_input(&self, example: &Example, input: &str) -> Result<(), ExamplesError> {
+        self.write(&example.file, input)
+    }
+
+    fn write(&self, file: &Path, contents: &str) -> Result<(), ExamplesError> {
+        let path = self.directory.join(file);
+        let write_error = |source| ExamplesError::Write {
+            path: path.clone(),
+            source,
+        };
+
+        fs::create_dir_all(&self.directory).map_err(write_error)?;
+        fs::write(&path, contents).map_err(write_error)
+    }
+
+    pub fn input(&self, example: &Example) -> Result<String, ExamplesError> {
+        let path = self.directory.join(&example.file);
+
+        fs::read_to_string(&path).map_err(|source| ExamplesError::Read { path, source })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_loads_examples_with_parts_that_do_not_apply() {
+        let directory = tempfile::tempdir().unwrap();
+
+        fs::write(directory.path().join("example-input.txt"), "L68\n").unwrap();
+        fs::write(
+            directory.path().join(EXAMPLES_FILE_NAME),
+            r#"
+            [[example]]
+            name = "example"
+            file = "example-input.txt"
+            part1 = 3
+            part2 = "6"
+
+            [[example]]
+            name = "full-turns"
+            file = "full-turns.txt"
+            part2 = 10
+            "#,
+        )
+        .unwrap();
+
+        let examples = Examples::load(directory.path()).unwrap();
+        let example = examples.get("example").unwrap();
+        let full_turns = examples.get("full-turns").unwrap();
+
+        assert_eq!(examples.examples().count(), 2);
+        assert_eq!(examples.input(example).unwrap(), "L68\n");
+        assert_eq!(example.expected(1), Some(&Answer::Number(3)));
+        assert_eq!(example.expected(2), Some(&Answer::Number(6)));
+        assert_eq!(full_turns.expected(1), None);
+        assert!(examples.input(full_turns).is_err());
+    }
+
+    #[test]
+    fn it_treats_a_missing_manifest_as_no_examples() {
+        let directory = tempfile::tempdir().unwrap();
+
+        assert!(Examples::load(directory.path()).unwrap().is_empty());
+    }
+}
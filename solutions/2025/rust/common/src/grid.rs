@@ -0,0 +1,230 @@
+use crate::{
+    error::GridError,
+    point::{Direction, Point},
+};
+use std::{
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        self.index_of(position).is_some()
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (&T, Point)> {
+        self.cells.iter().zip(self.positions())
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> + use<T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn orthogonal_neighbor_positions(
+        &self,
+        position: Point,
+    ) -> impl Iterator<Item = Point> + use<'_, T> {
+        self.offset_positions(position, &Direction::ORTHOGONAL)
+    }
+
+    pub fn neighbor_positions(&self, position: Point) -> impl Iterator<Item = Point> + use<'_, T> {
+        self.offset_positions(position, &Direction::ALL)
+    }
+
+    fn offset_positions(
+        &self,
+        position: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + use<'_, T> {
+        directions
+            .iter()
+            .map(move |&direction| position + direction)
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    fn index_of(&self, position: Point) -> Option<usize> {
+        position
+            .within_bounds(self.width, self.height)
+            .map(|(x, y)| x + (y * self.width))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &Self::Output {
+        self.get(position).expect("position out of grid bounds")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut Self::Output {
+        self.get_mut(position).expect("position out of grid bounds")
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError<T::Error>;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut width = 0usize;
+        let mut height = 0usize;
+        let mut cells = vec![];
+
+        for line in string.trim().lines() {
+            let row_start = cells.len();
+
+            for ch in line.chars() {
+                cells.push(T::try_from(ch).map_err(GridError::InvalidCell)?);
+            }
+
+            let row_width = cells.len() - row_start;
+
+            if height == 0 {
+                width = row_width;
+            } else if width != row_width {
+                return Err(GridError::JaggedInput);
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Cell(char);
+
+    impl TryFrom<char> for Cell {
+        type Error = char;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' | '#' => Ok(Self(value)),
+                _ => Err(value),
+            }
+        }
+    }
+
+    const EXAMPLE_INPUT: &str = "#..\n.#.\n..#";
+
+    #[test]
+    fn it_parses_cells_in_row_order() {
+        let grid = EXAMPLE_INPUT.parse::<Grid<Cell>>().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Point::new(1, 1)], Cell('#'));
+        assert_eq!(grid[Point::new(2, 1)], Cell('.'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn it_rejects_invalid_input() {
+        assert_eq!("#.\n#".parse::<Grid<Cell>>(), Err(GridError::JaggedInput));
+        assert_eq!("#x".parse::<Grid<Cell>>(), Err(GridError::InvalidCell('x')));
+    }
+
+    #[test]
+    fn it_iterates_rows_and_columns() {
+        let grid = EXAMPLE_INPUT.parse::<Grid<Cell>>().unwrap();
+        let rows = grid.rows().collect::<Vec<_>>();
+        let first_column = grid.columns().next().unwrap().collect::<Vec<_>>();
+
+        assert_eq!(rows[0], [Cell('#'), Cell('.'), Cell('.')]);
+        assert_eq!(first_column, [&Cell('#'), &Cell('.'), &Cell('.')]);
+    }
+
+    #[test]
+    fn it_clips_neighbors_to_grid_bounds() {
+        let grid = EXAMPLE_INPUT.parse::<Grid<Cell>>().unwrap();
+
+        assert_eq!(grid.neighbor_positions(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbor_positions(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.orthogonal_neighbor_positions(Point::ORIGIN)
+                .collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+    }
+
+    const RECTANGULAR_INPUT: &str = "#...#\n.#.#.";
+
+    #[test]
+    fn it_yields_positions_of_rectangular_grids_in_row_order() {
+        let grid = RECTANGULAR_INPUT.parse::<Grid<Cell>>().unwrap();
+        let walls = grid
+            .cells()
+            .filter(|&(&cell, _)| cell == Cell('#'))
+            .map(|(_, position)| position)
+            .collect::<Vec<_>>();
+
+        assert_eq!((grid.width(), grid.height()), (5, 2));
+        assert_eq!(grid.positions().count(), 10);
+        assert_eq!(grid.positions().last(), Some(Point::new(4, 1)));
+        assert_eq!(
+            walls,
+            [
+                Point::new(0, 0),
+                Point::new(4, 0),
+                Point::new(1, 1),
+                Point::new(3, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_indexes_rectangular_grids() {
+        let mut grid = RECTANGULAR_INPUT.parse::<Grid<Cell>>().unwrap();
+        grid[Point::new(4, 1)] = Cell('#');
+
+        assert_eq!(
+            grid.rows().nth(1),
+            Some(&[Cell('.'), Cell('#'), Cell('.'), Cell('#'), Cell('#')][..])
+        );
+        assert_eq!(grid.columns().count(), 5);
+        assert_eq!(grid.get(Point::new(1, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.neighbor_positions(Point::new(4, 1)).count(), 3);
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{
+    config::Config,
+    encryption::{ENCRYPTED_EXTENSION, InputKey},
+    error::InputError,
+    paths::Workspace,
+};
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Where to read a single input from, as given on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+
+                Ok(input)
+            }
+            Self::File(path) => read_file(path),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Ok(match string {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        })
+    }
+}
+
+/// Looks up puzzle inputs by year and day in an inputs directory laid out as
+/// `<year>/day<day>.txt`. Inputs may also be stored encrypted as `<year>/day<day>.txt.enc`, in
+/// which case they are decrypted with the key in the key file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputProvider {
+    directory: PathBuf,
+    key_file: Option<PathBuf>,
+}
+
+impl InputProvider {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            key_file: None,
+        }
+    }
+
+    pub fn with_key_file(mut self, key_file: impl Into<PathBuf>) -> Self {
+        self.key_file = Some(key_file.into());
+        self
+    }
+
+    /// Picks the inputs directory from, in order of precedence, the explicitly given path, the
+    /// `AOC_INPUT_DIR` environment variable, the config file and finally `inputs/` in the root of
+    /// the repository.
+    pub fn from_env(explicit: Option<PathBuf>) -> Result<Self, InputError> {
+        let config = Config::load(Workspace::locate(None)?)?;
+        Ok(Self::with_config(explicit, &config))
+    }
+
+    pub fn with_config(explicit: Option<PathBuf>, config: &Config) -> Self {
+        Self::resolve(explicit, env::var_os(INPUT_DIR_ENV), config)
+    }
+
+    pub fn resolve(explicit: Option<PathBuf>, env: Option<OsString>, config: &Config) -> Self {
+        let directory = explicit
+            .or_else(|| env.filter(|dir| !dir.is_empty()).map(PathBuf::from))
+            .or_else(|| config.input_dir.clone())
+            .unwrap_or_else(|| config.workspace.repository_dir().join("inputs"));
+
+        Self::new(directory).with_key_file(config.key_file())
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub fn key_file(&self) -> Option<&Path> {
+        self.key_file.as_deref()
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.directory.join(format!("{year}/day{day:02}.txt"))
+    }
+
+    pub fn encrypted_path(&self, year: u16, day: u8) -> PathBuf {
+        encrypted_path(&self.path(year, day))
+    }
+
+    /// Loads an input, falling back to its encrypted copy when there is no plain one.
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        let encrypted = encrypted_path(&path);
+
+        if path.exists() {
+            read_file(&path)
+        } else if encrypted.exists() {
+            self.read_encrypted(&encrypted)
+        } else {
+            Err(InputError::Missing { year, day, path })
+        }
+    }
+
+    /// Encrypts every plain input in the directory, removing the plain copies unless `keep` is
+    /// set. Returns the paths of the encrypted inputs.
+    pub fn encrypt_all(&self, key: &InputKey, keep: bool) -> Result<Vec<PathBuf>, InputError> {
+        let mut encrypted = Vec::new();
+
+        for path in self.files(".txt")? {
+            let target = encrypted_path(&path);
+            write_file(&target, &key.encrypt(read_file(&path)?.as_bytes()))?;
+
+            if !keep {
+                fs::remove_file(&path).map_err(|source| InputError::Write {
+                    path: path.clone(),
+                    source,
+                })?;
+            }
+
+            encrypted.push(target);
+        }
+
+        Ok(encrypted)
+    }
+
+    /// Writes a plain copy of every encrypted input in the directory, returning their paths.
+    pub fn decrypt_all(&self) -> Result<Vec<PathBuf>, InputError> {
+        let mut decrypted = Vec::new();
+
+        for path in self.files(&format!(".txt.{ENCRYPTED_EXTENSION}"))? {
+            let target = path.with_extension("");
+            write_file(&target, self.read_encrypted(&path)?.as_bytes())?;
+            decrypted.push(target);
+        }
+
+        Ok(decrypted)
+    }
+
+    fn key(&self, path: &Path) -> Result<InputKey, InputError> {
+        match &self.key_file {
+            Some(key_file) if key_file.exists() => Ok(InputKey::from_file(key_file)?),
+            key_file => Err(InputError::MissingKey {
+                path: path.to_path_buf(),
+                key_file: key_file.clone().unwrap_or_default(),
+            }),
+        }
+    }
+
+    fn read_encrypted(&self, path: &Path) -> Result<String, InputError> {
+        let data = fs::read(path).map_err(|source| InputError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        self.key(path)?
+            .decrypt(&data)
+            .and_then(|plaintext| String::from_utf8(plaintext).ok())
+            .ok_or_else(|| InputError::Decrypt {
+                path: path.to_path_buf(),
+            })
+    }
+
+    /// Files in the year directories whose name ends with `suffix`, sorted by path.
+    fn files(&self, suffix: &str) -> Result<Vec<PathBuf>, InputError> {
+        let read_dir = |path: &Path| {
+            fs::read_dir(path)
+                .and_then(|entries| {
+                    entries
+                        .map(|entry| entry.map(|entry| entry.path()))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .map_err(|source| InputError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+        };
+
+        if !self.directory.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+
+        for year in read_dir(&self.directory)? {
+            if year.is_dir() {
+                files.extend(read_dir(&year)?.into_iter().filter(|path| {
+                    path.is_file()
+                        && path
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .is_some_and(|name| name.ends_with(suffix))
+                }));
+            }
+        }
+
+        files.sort();
+        Ok(files)
+    }
+}
+
+fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_os_string();
+    encrypted.push(format!(".{ENCRYPTED_EXTENSION}"));
+
+    PathBuf::from(encrypted)
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<(), InputError> {
+    fs::write(path, contents).map_err(|source| InputError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_input_sources() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "day04.txt".parse(),
+            Ok(InputSource::File(PathBuf::from("day04.txt")))
+        );
+    }
+
+    #[test]
+    fn it_prefers_explicit_then_env_then_config_directories() {
+        let config = Config {
+            input_dir: Some(PathBuf::from("/config")),
+            ..Config::default()
+        };
+
+        let provider = |explicit: Option<&str>, env: Option<&str>| {
+            InputProvider::resolve(
+                explicit.map(PathBuf::from),
+                env.map(OsString::from),
+                &config,
+            )
+        };
+
+        assert_eq!(
+            provider(Some("/explicit"), Some("/env")).directory(),
+            Path::new("/explicit")
+        );
+        assert_eq!(provider(None, Some("/env")).directory(), Path::new("/env"));
+        assert_eq!(provider(None, Some("")).directory(), Path::new("/config"));
+        assert_eq!(provider(None, None).directory(), Path::new("/config"));
+        let config = Config {
+            workspace: Workspace::new("/aoc/solutions/2025/rust"),
+            ..Config::default()
+        };
+
+        assert_eq!(
+            InputProvider::resolve(None, None, &config).directory(),
+            Path::new("/aoc/inputs")
+        );
+    }
+
+    #[test]
+    fn it_reports_missing_inputs() {
+        let provider = InputProvider::new("/aoc/missing");
+        let path = provider.path(2025, 4);
+
+        assert!(path.ends_with("missing/2025/day04.txt"));
+        assert!(matches!(
+            provider.load(2025, 4),
+            Err(InputError::Missing { year: 2025, day: 4, path: missing }) if missing == path
+        ));
+    }
+
+    #[test]
+    fn it_reads_encrypted_inputs() {
+        let directory = tempfile::tempdir().unwrap();
+        let key_file = directory.path().join("inputs.key");
+        let provider = InputProvider::new(directory.path().join("inputs")).with_key_file(&key_file);
+        let key = InputKey::generate();
+
+        fs::create_dir_all(directory.path().join("inputs/2025")).unwrap();
+        fs::write(provider.path(2025, 5), "3-5\n\n1\n").unwrap();
+
+        let encrypted = provider.encrypt_all(&key, false).unwrap();
+
+        assert_eq!(
+            encrypted,
+            [directory.path().join("inputs/2025/day05.txt.enc")]
+        );
+        assert!(!provider.path(2025, 5).exists());
+        assert!(matches!(
+            provider.load(2025, 5),
+            Err(InputError::MissingKey { .. })
+        ));
+
+        key.save(&key_file).unwrap();
+        assert_eq!(provider.load(2025, 5).unwrap(), "3-5\n\n1\n");
+
+        assert_eq!(provider.decrypt_all().unwrap(), [provider.path(2025, 5)]);
+        assert_eq!(
+            fs::read_to_string(provider.path(2025, 5)).unwrap(),
+            "3-5\n\n1\n"
+        );
+
+        InputKey::generate().save(&key_file).unwrap();
+        fs::remove_file(provider.path(2025, 5)).unwrap();
+        assert!(matches!(
+            provider.load(2025, 5),
+            Err(InputError::Decrypt { .. })
+        ));
+    }
+}
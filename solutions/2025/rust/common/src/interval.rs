@@ -0,0 +1,229 @@
+use num::PrimInt;
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, disjoint and non-adjacent inclusive ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T: PrimInt> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new(ranges: impl IntoIterator<Item = RangeInclusive<T>>) -> Self {
+        let mut ranges = ranges
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<_>>();
+
+        ranges.sort_by_key(|range| *range.start());
+
+        let mut merged: Vec<RangeInclusive<T>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if *range.start() <= last.end().saturating_add(T::one()) => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                }
+                _ => merged.push(range),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| *range.end() < value);
+
+        self.ranges
+            .get(index)
+            .is_some_and(|range| *range.start() <= value)
+    }
+
+    /// Returns the amount of values covered by the set, saturating at `u128::MAX` for a set that
+    /// covers every `u128`.
+    pub fn covered_len(&self) -> u128 {
+        self.ranges.iter().fold(0, |total: u128, range| {
+            let (start, end) = (*range.start(), *range.end());
+
+            let distance = match (start.to_i128(), end.to_i128()) {
+                (Some(start), Some(end)) => end.abs_diff(start),
+                // Only unsigned values are beyond `i128`, so the distance fits the type itself.
+                _ => (end - start).to_u128().unwrap_or(u128::MAX),
+            };
+
+            total.saturating_add(distance).saturating_add(1)
+        })
+    }
+
+    /// Iterates over the ranges that lie between two consecutive ranges of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges
+            .windows(2)
+            .map(|pair| (*pair[0].end() + T::one())..=(*pair[1].start() - T::one()))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.ranges().chain(other.ranges()))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            if a.end() < b.end() {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(*first.start()..=*last.end()))
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns every value within `bounds` that is not part of the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        if bounds.is_empty() {
+            return Self { ranges: vec![] };
+        }
+
+        let (lower, upper) = (*bounds.start(), *bounds.end());
+        let mut ranges = vec![];
+        let mut next_start = Some(lower);
+
+        for range in &self.ranges {
+            let Some(start) = next_start else {
+                break;
+            };
+
+            if *range.end() < start {
+                continue;
+            }
+
+            if *range.start() > upper {
+                break;
+            }
+
+            if *range.start() > start {
+                ranges.push(start..=(*range.start() - T::one()));
+            }
+
+            next_start = range.end().checked_add(&T::one());
+        }
+
+        if let Some(start) = next_start.filter(|&start| start <= upper) {
+            ranges.push(start..=upper);
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: PrimInt> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iterator: I) -> Self {
+        Self::new(iterator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn it_merges_overlapping_and_adjacent_ranges() {
+        let set = IntervalSet::new([3..=5, 10..=14, 16..=20, 12..=18, 6..=6, 0..=0]);
+
+        assert_eq!(ranges(&set), [0..=0, 3..=6, 10..=20]);
+        assert_eq!(set.covered_len(), 16);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [1..=2, 7..=9]);
+    }
+
+    #[test]
+    fn it_measures_sets_covering_a_whole_type() {
+        assert_eq!(IntervalSet::new([i8::MIN..=i8::MAX]).covered_len(), 256);
+        assert_eq!(
+            IntervalSet::new([i8::MIN..=-1, 1..=i8::MAX]).covered_len(),
+            255
+        );
+        assert_eq!(IntervalSet::new([0..=u64::MAX]).covered_len(), 1 << 64);
+        assert_eq!(
+            IntervalSet::new([0..=u128::MAX - 1, u128::MAX..=u128::MAX]).covered_len(),
+            u128::MAX
+        );
+        assert_eq!(IntervalSet::<i32>::default().covered_len(), 0);
+    }
+
+    #[test]
+    fn it_checks_membership() {
+        let set = IntervalSet::new([0..=0, 3..=5, 10..=14]);
+
+        assert!(set.contains(0));
+        assert!(set.contains(5));
+        assert!(set.contains(10));
+        assert!(!set.contains(1));
+        assert!(!set.contains(15));
+        assert!(!IntervalSet::<i32>::default().contains(0));
+    }
+
+    #[test]
+    fn it_combines_sets() {
+        let a = IntervalSet::new([0..=4, 8..=12]);
+        let b = IntervalSet::new([3..=9, 12..=20]);
+
+        assert_eq!(ranges(&a.union(&b)), [0..=20]);
+        assert_eq!(ranges(&a.intersection(&b)), [3..=4, 8..=9, 12..=12]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=2, 10..=11]);
+        assert_eq!(ranges(&b.difference(&a)), [5..=7, 13..=20]);
+    }
+
+    #[test]
+    fn it_complements_within_bounds() {
+        let set = IntervalSet::new([i32::MIN..=-5, 0..=4, 8..=i32::MAX]);
+
+        assert_eq!(ranges(&set.complement(-10..=10)), [-4..=-1, 5..=7]);
+        assert_eq!(ranges(&set.complement(1..=3)), []);
+        assert_eq!(
+            ranges(&set.complement(i32::MIN..=i32::MAX)),
+            [-4..=-1, 5..=7]
+        );
+        assert_eq!(ranges(&IntervalSet::default().complement(1..=3)), [1..=3]);
+    }
+}
@@ -0,0 +1,28 @@
+pub mod allocations;
+pub mod answers;
+pub mod config;
+pub mod encryption;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod paths;
+pub mod point;
+pub mod registry;
+pub mod solution;
+pub mod testing;
+pub mod trace;
+
+pub use crate::{
+    answers::{ExpectedAnswer, KnownAnswers},
+    config::Config,
+    examples::{Example, Examples},
+    grid::Grid,
+    input::{InputProvider, InputSource},
+    interval::IntervalSet,
+    paths::Workspace,
+    point::{Direction, Point},
+    registry::Registry,
+    solution::{Answer, Budget, Solution},
+};
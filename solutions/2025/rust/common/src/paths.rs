@@ -0,0 +1,117 @@
+use crate::{config::CONFIG_FILE_NAME, error::WorkspaceError};
+use std::{
+    env, fs,
+    path::{self, Path, PathBuf},
+};
+
+pub const WORKSPACE_ENV: &str = "AOC_WORKSPACE";
+
+/// The cargo workspace of a year, which holds one crate per day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    dir: PathBuf,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self::new(".")
+    }
+}
+
+impl Workspace {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Picks the workspace from, in order of precedence, the explicitly given directory, the
+    /// `AOC_WORKSPACE` environment variable and the closest directory above the current one that
+    /// holds the workspace manifest or a config file.
+    pub fn locate(explicit: Option<PathBuf>) -> Result<Self, WorkspaceError> {
+        let explicit = explicit.or_else(|| {
+            env::var_os(WORKSPACE_ENV)
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from)
+        });
+
+        if let Some(dir) = explicit {
+            let dir = path::absolute(&dir).map_err(WorkspaceError::CurrentDir)?;
+
+            return match Self::is_workspace(&dir) {
+                true => Ok(Self::new(dir)),
+                false => Err(WorkspaceError::NotAWorkspace(dir)),
+            };
+        }
+
+        let current = env::current_dir().map_err(WorkspaceError::CurrentDir)?;
+        Self::find(&current).ok_or(WorkspaceError::NotFound(current))
+    }
+
+    /// Walks up from a directory to the first one that holds the workspace manifest or a config
+    /// file.
+    pub fn find(start: &Path) -> Option<Self> {
+        start
+            .ancestors()
+            .find(|dir| Self::is_workspace(dir))
+            .map(Self::new)
+    }
+
+    fn is_workspace(dir: &Path) -> bool {
+        dir.join(CONFIG_FILE_NAME).is_file()
+            || fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The root of the repository when the workspace is nested in it as
+    /// `solutions/<year>/<language>`, or the workspace itself when it is laid out differently.
+    pub fn repository_dir(&self) -> PathBuf {
+        self.dir
+            .ancestors()
+            .nth(3)
+            .filter(|_| {
+                self.dir
+                    .ancestors()
+                    .nth(2)
+                    .and_then(Path::file_name)
+                    .is_some_and(|name| name == "solutions")
+            })
+            .unwrap_or(&self.dir)
+            .to_path_buf()
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day:02}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_the_workspace_above_a_day() {
+        let root = tempfile::tempdir().unwrap();
+        let workspace = root.path().join("solutions/2025/rust");
+        let day = workspace.join("day01/src");
+
+        fs::create_dir_all(&day).unwrap();
+        fs::write(workspace.join("Cargo.toml"), "[workspace]\nmembers = []\n").unwrap();
+        fs::write(workspace.join("day01/Cargo.toml"), "[package]\n").unwrap();
+
+        let found = Workspace::find(&day).unwrap();
+
+        assert_eq!(found.dir(), workspace);
+        assert_eq!(found.repository_dir(), root.path());
+        assert_eq!(found.day_dir(1), workspace.join("day01"));
+        assert_eq!(Workspace::find(root.path()), None);
+    }
+
+    #[test]
+    fn it_falls_back_to_the_workspace_outside_of_the_repository_layout() {
+        let workspace = Workspace::new("/home/elf/advent");
+        assert_eq!(workspace.repository_dir(), Path::new("/home/elf/advent"));
+    }
+}
@@ -0,0 +1,191 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Converts the point into unsigned coordinates if it lies within a `width` by `height` area
+    /// anchored at the origin.
+    pub fn within_bounds(self, width: usize, height: usize) -> Option<(usize, usize)> {
+        let x = usize::try_from(self.x).ok().filter(|&x| x < width)?;
+        let y = usize::try_from(self.y).ok().filter(|&y| y < height)?;
+
+        Some((x, y))
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl TryFrom<(usize, usize)> for Point {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from((x, y): (usize, usize)) -> Result<Self, Self::Error> {
+        Ok(Self::new(x.try_into()?, y.try_into()?))
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self::Output {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self::Output {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// A step on a grid where `y` grows downwards, matching the order in which input lines are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Left, Self::Right, Self::Down];
+
+    pub const ALL: [Self; 8] = [
+        Self::UpLeft,
+        Self::Up,
+        Self::UpRight,
+        Self::Left,
+        Self::Right,
+        Self::DownLeft,
+        Self::Down,
+        Self::DownRight,
+    ];
+
+    pub const fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::Right => Point::new(1, 0),
+            Self::UpLeft => Point::new(-1, -1),
+            Self::UpRight => Point::new(1, -1),
+            Self::DownLeft => Point::new(-1, 1),
+            Self::DownRight => Point::new(1, 1),
+        }
+    }
+
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::UpLeft => Self::DownRight,
+            Self::UpRight => Self::DownLeft,
+            Self::DownLeft => Self::UpRight,
+            Self::DownRight => Self::UpLeft,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_does_arithmetic() {
+        let point = Point::new(2, 3);
+
+        assert_eq!(point + Point::new(-1, 1), Point::new(1, 4));
+        assert_eq!(point - Point::new(5, 5), Point::new(-3, -2));
+        assert_eq!(point + Direction::Up, Point::new(2, 2));
+        assert_eq!(point + Direction::Up.opposite(), Point::new(2, 4));
+        assert_eq!(-point * 2, Point::new(-4, -6));
+    }
+
+    #[test]
+    fn it_measures_distances() {
+        let from = Point::new(-2, 1);
+        let to = Point::new(3, -1);
+
+        assert_eq!(from.manhattan_distance(to), 7);
+        assert_eq!(from.chebyshev_distance(to), 5);
+    }
+
+    #[test]
+    fn it_converts_into_bounds() {
+        assert_eq!(Point::new(3, 1).within_bounds(4, 2), Some((3, 1)));
+        assert_eq!(Point::new(4, 1).within_bounds(4, 2), None);
+        assert_eq!(Point::new(0, 2).within_bounds(4, 2), None);
+        assert_eq!(Point::new(-1, 0).within_bounds(4, 2), None);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::solution::{DynSolution, Erased, Solution};
+use std::collections::BTreeMap;
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a day, replacing any solution previously registered for the same year and day.
+    pub fn register<S>(&mut self) -> &mut Self
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        self.solutions
+            .insert((S::YEAR, S::DAY), Box::new(Erased::<S>::new()));
+
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn DynSolution> {
+        self.solutions.get(&(year, day)).map(Box::as_ref)
+    }
+
+    /// Iterates over every registered day, ordered by year and then by day.
+    pub fn solutions(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions.values().map(Box::as_ref)
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions
+            .range((year, u8::MIN)..=(year, u8::MAX))
+            .map(|(_, solution)| solution.as_ref())
+    }
+
+    pub fn latest_year(&self) -> Option<u16> {
+        self.solutions.keys().next_back().map(|&(year, _)| year)
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+    use std::{convert::Infallible, num::ParseIntError};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 1;
+
+        type Input = Vec<i32>;
+        type Error = ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            input.split_whitespace().map(str::parse).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+            Ok(input.iter().sum::<i32>().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Option<Answer>, Self::Error> {
+            Ok(Some(input.iter().product::<i32>().into()))
+        }
+    }
+
+    struct Finale;
+
+    impl Solution for Finale {
+        const YEAR: u16 = 2025;
+        const DAY: u8 = 12;
+
+        type Input = String;
+        type Error = Infallible;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            Ok(input.trim().to_string())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+            Ok(input.as_str().into())
+        }
+    }
+
+    #[test]
+    fn it_runs_registered_solutions() {
+        let mut registry = Registry::new();
+        registry.register::<Finale>().register::<Sum>();
+
+        let sum = registry.get(2024, 1).unwrap();
+        let input = sum.parse("2 3 4").unwrap();
+
+        assert_eq!(sum.part1(input.as_ref()).unwrap(), Answer::Number(9));
+        assert_eq!(sum.part2(input.as_ref()).unwrap(), Some(Answer::Number(24)));
+        assert!(sum.parse("2 x").is_err());
+        assert!(registry.get(2024, 2).is_none());
+
+        let finale = registry.get(2025, 12).unwrap();
+        let input = finale.parse("stars").unwrap();
+
+        assert_eq!(finale.part2(input.as_ref()).unwrap(), None);
+    }
+
+    #[test]
+    fn it_orders_solutions_by_year_and_day() {
+        let mut registry = Registry::new();
+        registry.register::<Finale>().register::<Sum>();
+
+        let days = registry
+            .solutions()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(days, [(2024, 1), (2025, 12)]);
+        assert_eq!(registry.year(2025).count(), 1);
+        assert_eq!(registry.latest_year(), Some(2025));
+    }
+}
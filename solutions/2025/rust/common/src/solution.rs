@@ -0,0 +1,222 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    any::Any,
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+    time::Duration,
+};
+
+pub type BoxedError = Box<dyn Error + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(formatter, "{number}"),
+            Self::Text(text) => write!(formatter, "{text}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Self::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+/// Keeps numbers beyond the range of [`Answer::Number`] as their decimal text.
+impl From<u128> for Answer {
+    fn from(number: u128) -> Self {
+        i128::try_from(number).map_or_else(|_| Self::Text(number.to_string()), Self::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+/// Parses numbers as [`Answer::Number`] and anything else as [`Answer::Text`].
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+
+        Ok(string
+            .parse::<i128>()
+            .map_or_else(|_| Self::Text(string.to_string()), Self::Number))
+    }
+}
+
+/// Numbers are written as integers where TOML allows it and as strings otherwise.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Number(number) => match i64::try_from(*number) {
+                Ok(number) => serializer.serialize_i64(number),
+                Err(_) => serializer.collect_str(number),
+            },
+            Self::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+/// Accepts integers as well as strings, which are parsed like [`Answer::from_str`].
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(i64),
+            Text(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Number(number) => number.into(),
+            Raw::Text(text) => text.parse().unwrap_or_else(|error| match error {}),
+        })
+    }
+}
+
+/// How long the phases of a day may take on the actual input in a release build.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub parse: Option<Duration>,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Budget {
+    pub const NONE: Self = Self {
+        parse: None,
+        part1: None,
+        part2: None,
+    };
+
+    /// The budget of a part, `0` being the parse step.
+    pub fn phase(&self, phase: u8) -> Option<Duration> {
+        match phase {
+            0 => self.parse,
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+}
+
+/// A single day of a year, split into a parse step that is shared by both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Time budget enforced by the tests generated with `aoc_test!` when built in release mode.
+    const BUDGET: Budget = Budget::NONE;
+
+    type Input;
+    type Error: Error + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error>;
+
+    /// Solves the second part, returning `None` for days without one. The final day of a year
+    /// only has one part, in which case this does not need to be implemented.
+    fn part2(_input: &Self::Input) -> Result<Option<Answer>, Self::Error> {
+        Ok(None)
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer, Self::Error> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    fn solve_part2(input: &str) -> Result<Option<Answer>, Self::Error> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+/// Object safe counterpart of [`Solution`] used to store days of different types side by side.
+/// The parsed input is passed between the phases as [`Any`].
+pub trait DynSolution: Send + Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn budget(&self) -> Budget;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, BoxedError>;
+
+    fn part1(&self, input: &dyn Any) -> Result<Answer, BoxedError>;
+
+    fn part2(&self, input: &dyn Any) -> Result<Option<Answer>, BoxedError>;
+}
+
+pub(crate) struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S> Erased<S> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    fn downcast(input: &dyn Any) -> &S::Input
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        input
+            .downcast_ref()
+            .expect("parsed input passed to a different day than the one that parsed it")
+    }
+}
+
+impl<S> DynSolution for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn budget(&self) -> Budget {
+        S::BUDGET
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, BoxedError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<Answer, BoxedError> {
+        Ok(S::part1(Self::downcast(input))?)
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<Option<Answer>, BoxedError> {
+        Ok(S::part2(Self::downcast(input))?)
+    }
+}
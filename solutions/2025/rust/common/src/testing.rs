@@ -0,0 +1,222 @@
+use crate::{
+    answers::{ExpectedAnswer, KnownAnswers},
+    config::Config,
+    encryption::InputKey,
+    error::InputError,
+    examples::Examples,
+    input::InputProvider,
+    paths::Workspace,
+    solution::{Answer, Solution},
+};
+use std::{
+    hint::black_box,
+    io::{self, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Number of timed runs of which the fastest has to stay within the budget.
+const BUDGET_RUNS: usize = 5;
+
+/// Loads the actual input of a part together with the answer it is known to produce.
+///
+/// Returns `None` after reporting why the test is skipped when the input, its known answer or
+/// the key to decrypt either of them is not available, so that a fresh clone can still run the
+/// tests. The reason is written to stderr directly since the test harness hides captured output
+/// of tests that pass.
+pub fn actual_case(
+    year: u16,
+    day: u8,
+    part: u8,
+) -> Option<(String, ExpectedAnswer, Option<InputKey>)> {
+    let config = load_config();
+    let input = actual_input(&config, year, day, part)?;
+    let answers = KnownAnswers::load(&config).expect("failed to load known answers");
+
+    match answers.get(year, day, part, &input) {
+        Some(known)
+            if matches!(known.answer, ExpectedAnswer::Hashed { .. }) && answers.key().is_none() =>
+        {
+            let reason = format!(
+                "the known answer is hashed but there is no key at '{}'",
+                config.key_file().display()
+            );
+            skip(year, day, part, &reason);
+            None
+        }
+        Some(known) => Some((input, known.answer.clone(), answers.key().cloned())),
+        None => {
+            skip(year, day, part, "no known answer for this input");
+            None
+        }
+    }
+}
+
+fn load_config() -> Config {
+    let workspace = Workspace::locate(None).unwrap_or_else(|error| panic!("{error}"));
+    Config::load(workspace).expect("failed to load config")
+}
+
+fn actual_input(config: &Config, year: u16, day: u8, part: u8) -> Option<String> {
+    match InputProvider::with_config(None, config).load(year, day) {
+        Ok(input) => Some(input),
+        Err(error @ (InputError::Missing { .. } | InputError::MissingKey { .. })) => {
+            skip(year, day, part, &error.to_string());
+            None
+        }
+        Err(error) => panic!("failed to load input: {error}"),
+    }
+}
+
+/// Solves a part of every example in the manifest of the crate at `manifest_dir` that has an
+/// expected answer for it, panicking on the first example that does not produce its answer.
+pub fn check_examples<S: Solution>(part: u8, manifest_dir: &str) {
+    let examples = Examples::load(&Path::new(manifest_dir).join("data"))
+        .unwrap_or_else(|error| panic!("{error}"));
+    let mut checked = 0;
+
+    for example in examples.examples() {
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+
+        let input = examples
+            .input(example)
+            .unwrap_or_else(|error| panic!("{error}"));
+
+        assert_eq!(
+            solve::<S>(part, &input),
+            *expected,
+            "part {part} of example '{}'",
+            example.name
+        );
+
+        checked += 1;
+    }
+
+    if checked == 0 {
+        skip(S::YEAR, S::DAY, part, "no example applies to this part");
+    }
+}
+
+/// Solves a part of the actual input, unless [`actual_case`] reports it as skipped.
+pub fn check_actual_input<S: Solution>(part: u8) {
+    if let Some((input, expected, key)) = actual_case(S::YEAR, S::DAY, part) {
+        let answer = solve::<S>(part, &input);
+
+        assert_eq!(
+            expected.matches(&answer, key.as_ref()),
+            Some(true),
+            "{answer} does not match the known answer {expected}"
+        );
+    }
+}
+
+/// Checks that the parse step and a part stay within the budget of the day on the actual input.
+/// Only release builds are checked, since debug builds are not representative.
+pub fn check_budget<S: Solution>(part: u8) {
+    let budget = S::BUDGET;
+
+    if budget.parse.is_none() && budget.phase(part).is_none() {
+        return;
+    }
+
+    if cfg!(debug_assertions) {
+        skip(
+            S::YEAR,
+            S::DAY,
+            part,
+            "budgets are only checked in release builds",
+        );
+        return;
+    }
+
+    let config = load_config();
+    let Some(input) = actual_input(&config, S::YEAR, S::DAY, part) else {
+        return;
+    };
+
+    let mut fastest = [Duration::MAX; 2];
+
+    for _ in 0..BUDGET_RUNS {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(&input).unwrap_or_else(|error| panic!("{error}")));
+        let parsed_at = Instant::now();
+
+        let answer = match part {
+            1 => S::part1(&parsed).map(Some),
+            _ => S::part2(&parsed),
+        };
+
+        let solved_at = Instant::now();
+        black_box(answer.unwrap_or_else(|error| panic!("{error}")));
+
+        fastest[0] = fastest[0].min(parsed_at - start);
+        fastest[1] = fastest[1].min(solved_at - parsed_at);
+    }
+
+    for (phase, name, elapsed) in [
+        (0, "parse".to_string(), fastest[0]),
+        (part, format!("part {part}"), fastest[1]),
+    ] {
+        if let Some(limit) = budget.phase(phase) {
+            assert!(
+                elapsed <= limit,
+                "{name} took {elapsed:?}, which is over its budget of {limit:?}"
+            );
+        }
+    }
+}
+
+fn solve<S: Solution>(part: u8, input: &str) -> Answer {
+    match part {
+        1 => S::solve_part1(input).map(Some),
+        _ => S::solve_part2(input),
+    }
+    .unwrap_or_else(|error| panic!("failed to solve part {part}: {error}"))
+    .unwrap_or_else(|| panic!("day {} of {} has no second part", S::DAY, S::YEAR))
+}
+
+fn skip(year: u16, day: u8, part: u8, reason: &str) {
+    let _ = writeln!(
+        io::stderr(),
+        "skipped {year} day {day:02} part {part}: {reason}"
+    );
+}
+
+/// Generates the tests of a day, `part1::it_works_on_examples`, `part1::it_works_on_actual_input`
+/// and `part1::it_stays_within_budget` and the same for `part2`. The examples and their expected
+/// answers are read from `data/examples.toml` in the day's crate. The tests of the second part
+/// are skipped for days without one as long as no example or known answer expects it.
+#[macro_export]
+macro_rules! aoc_test {
+    ($solution:ty) => {
+        mod part1 {
+            use super::*;
+
+            $crate::aoc_test!(@part $solution, 1);
+        }
+
+        mod part2 {
+            use super::*;
+
+            $crate::aoc_test!(@part $solution, 2);
+        }
+    };
+    (@part $solution:ty, $part:literal) => {
+        #[test]
+        fn it_works_on_examples() {
+            $crate::testing::check_examples::<$solution>($part, env!("CARGO_MANIFEST_DIR"));
+        }
+
+        #[test]
+        fn it_works_on_actual_input() {
+            $crate::testing::check_actual_input::<$solution>($part);
+        }
+
+        #[test]
+        fn it_stays_within_budget() {
+            $crate::testing::check_budget::<$solution>($part);
+        }
+    };
+}
@@ -0,0 +1,143 @@
+use serde::Serialize;
+use std::{
+    borrow::Cow,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Instant,
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+static NEXT_THREAD: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static THREAD: u64 = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+}
+
+struct Recorder {
+    start: Instant,
+    events: Vec<Event>,
+}
+
+/// A complete event of the Chrome trace event format, with its timestamp and duration in
+/// microseconds since recording started.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Event {
+    pub name: Cow<'static, str>,
+    #[serde(rename = "ph")]
+    pub phase: &'static str,
+    #[serde(rename = "ts")]
+    pub timestamp: f64,
+    #[serde(rename = "dur")]
+    pub duration: f64,
+    pub pid: u32,
+    pub tid: u64,
+}
+
+/// The events of a recording, which serializes to JSON that `chrome://tracing` and Perfetto open.
+#[derive(Debug, Serialize)]
+pub struct Trace {
+    #[serde(rename = "traceEvents")]
+    pub events: Vec<Event>,
+}
+
+/// Records the time between its creation and being dropped, if recording is enabled. Spans that
+/// are created while another one is alive on the same thread show up nested in it.
+#[must_use = "the span ends when it is dropped"]
+pub struct Span {
+    started: Option<(Cow<'static, str>, Instant)>,
+}
+
+/// Starts recording spans from every thread, discarding anything recorded before.
+pub fn start() {
+    *lock() = Some(Recorder {
+        start: Instant::now(),
+        events: Vec::new(),
+    });
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Stops recording and returns the spans recorded since [`start`].
+pub fn finish() -> Trace {
+    ENABLED.store(false, Ordering::Release);
+
+    let events = lock()
+        .take()
+        .map_or_else(Vec::new, |recorder| recorder.events);
+
+    Trace { events }
+}
+
+/// Opens a span, which costs next to nothing while recording is disabled so that solutions can
+/// mark the interesting parts of their work unconditionally.
+pub fn span(name: impl Into<Cow<'static, str>>) -> Span {
+    let started = ENABLED
+        .load(Ordering::Acquire)
+        .then(|| (name.into(), Instant::now()));
+
+    Span { started }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some((name, started)) = self.started.take() else {
+            return;
+        };
+
+        let ended = Instant::now();
+
+        if let Some(recorder) = lock().as_mut() {
+            let since_start = |instant: Instant| {
+                instant
+                    .saturating_duration_since(recorder.start)
+                    .as_secs_f64()
+                    * 1e6
+            };
+
+            recorder.events.push(Event {
+                name,
+                phase: "X",
+                timestamp: since_start(started),
+                duration: (ended - started).as_secs_f64() * 1e6,
+                pid: 1,
+                tid: THREAD.with(|thread| *thread),
+            });
+        }
+    }
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Recorder>> {
+    RECORDER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_records_nested_spans_while_enabled() {
+        drop(span("ignored"));
+        start();
+
+        {
+            let _outer = span("outer");
+            drop(span(format!("inner {}", 1)));
+        }
+
+        let events = finish().events;
+        drop(span("ignored"));
+
+        let names = events.iter().map(|event| &*event.name).collect::<Vec<_>>();
+        assert_eq!(names, ["inner 1", "outer"]);
+
+        let (inner, outer) = (&events[0], &events[1]);
+        assert!(outer.timestamp <= inner.timestamp);
+        assert!(inner.timestamp + inner.duration <= outer.timestamp + outer.duration);
+        assert_eq!(inner.tid, outer.tid);
+        assert!(finish().events.is_empty());
+    }
+}
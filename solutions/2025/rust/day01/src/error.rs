@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("invalid rotation specified, expected 'L' or 'R' followed by a number but got '{0}'")]
+    InvalidRotation(String),
+}
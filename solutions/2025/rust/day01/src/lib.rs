@@ -0,0 +1,96 @@
+use crate::error::Error;
+use aoc_common::{Answer, Solution};
+
+pub mod error;
+
+const MAX: i32 = 99;
+const INITIAL_VALUE: i32 = 50;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut rotations = vec![];
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let invalid_rotation = || Error::InvalidRotation(line.to_string());
+            let (direction, amount_string) =
+                line.split_at_checked(1).ok_or_else(invalid_rotation)?;
+
+            let amount = amount_string
+                .parse::<i32>()
+                .map_err(|_| invalid_rotation())?;
+
+            rotations.push(match direction {
+                "L" => -amount,
+                "R" => amount,
+                _ => return Err(invalid_rotation()),
+            });
+        }
+
+        Ok(rotations)
+    }
+
+    fn part1(rotations: &Self::Input) -> Result<Answer, Self::Error> {
+        let mut value = INITIAL_VALUE;
+        let mut amount_of_zeroes = 0usize;
+
+        for amount in rotations {
+            value += amount;
+            value = value.rem_euclid(MAX + 1);
+
+            if value == 0 {
+                amount_of_zeroes += 1;
+            }
+        }
+
+        Ok(amount_of_zeroes.into())
+    }
+
+    fn part2(rotations: &Self::Input) -> Result<Option<Answer>, Self::Error> {
+        let mut value = INITIAL_VALUE;
+        let mut amount_of_zeroes = 0;
+
+        for amount in rotations {
+            let previous = value;
+            value += amount;
+
+            if value > 0 {
+                amount_of_zeroes += value / 100;
+            } else if previous == 0 {
+                amount_of_zeroes += value.abs() / 100;
+            } else {
+                amount_of_zeroes += (value.abs() / 100) + 1;
+            }
+
+            value = value.rem_euclid(MAX + 1);
+        }
+
+        Ok(Some(amount_of_zeroes.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::aoc_test!(Day01);
+
+    #[test]
+    fn it_rejects_invalid_rotations() {
+        assert_eq!(
+            Day01::parse("L68\nU3"),
+            Err(Error::InvalidRotation("U3".to_string()))
+        );
+        assert_eq!(
+            Day01::parse("ÄR3"),
+            Err(Error::InvalidRotation("ÄR3".to_string()))
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("invalid id range specified, expected two numbers separated by '-' but got '{0}'")]
+    InvalidRange(String),
+}
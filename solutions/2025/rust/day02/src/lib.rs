@@ -0,0 +1,106 @@
+use crate::error::Error;
+use aoc_common::{Answer, Budget, Solution};
+use num::Integer;
+use std::{collections::HashSet, ops::RangeInclusive, time::Duration};
+
+pub mod error;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    const BUDGET: Budget = Budget {
+        part1: Some(Duration::from_millis(50)),
+        part2: Some(Duration::from_millis(50)),
+        ..Budget::NONE
+    };
+
+    type Input = Vec<RangeInclusive<usize>>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut ranges = vec![];
+
+        for range_string in input.trim().split(',') {
+            let invalid_range = || Error::InvalidRange(range_string.to_string());
+
+            let (start_string, end_string) =
+                range_string.split_once('-').ok_or_else(invalid_range)?;
+
+            let start = start_string.parse::<usize>().map_err(|_| invalid_range())?;
+
+            let end = end_string.parse::<usize>().map_err(|_| invalid_range())?;
+
+            ranges.push(start..=end);
+        }
+
+        Ok(ranges)
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<Answer, Self::Error> {
+        let mut invalid_ids = vec![];
+
+        for range in ranges {
+            for number in range.clone() {
+                if is_repeating_twice(number) {
+                    invalid_ids.push(number);
+                }
+            }
+        }
+
+        Ok(invalid_ids.iter().sum::<usize>().into())
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<Option<Answer>, Self::Error> {
+        let mut invalid_ids = vec![];
+
+        for range in ranges {
+            for number in range.clone() {
+                if is_repeating(number) {
+                    invalid_ids.push(number);
+                }
+            }
+        }
+
+        Ok(Some(invalid_ids.iter().sum::<usize>().into()))
+    }
+}
+
+fn is_repeating_twice(number: impl Integer + ToString) -> bool {
+    let string = number.to_string();
+
+    if !string.len().is_multiple_of(2) {
+        return false;
+    }
+
+    let split_part = string.len() / 2;
+    let (first, second) = string.split_at(split_part);
+
+    first == second
+}
+
+fn is_repeating(number: impl Integer + ToString) -> bool {
+    let string = number.to_string();
+
+    for index in (1..string.len()).filter(|&index| string.len().is_multiple_of(index)) {
+        let parts = (0..string.len())
+            .step_by(index)
+            .map(|split| &string[split..(split + index)])
+            .collect::<HashSet<_>>();
+
+        if parts.len() == 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::aoc_test!(Day02);
+}
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("invalid battery found, expected a digit but got '{0}'")]
+    InvalidBattery(char),
+    #[error("bank '{0}' has fewer than the 12 batteries that have to be turned on")]
+    TooFewBatteries(String),
+}
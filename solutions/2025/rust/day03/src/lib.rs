@@ -0,0 +1,110 @@
+use crate::error::Error;
+use aoc_common::{Answer, Solution};
+use num::Integer;
+
+pub mod error;
+
+/// Number of batteries turned on in each bank by the first and the second part.
+const PART1_BATTERIES: usize = 2;
+const PART2_BATTERIES: usize = 12;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u32>>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let bank = line
+                    .chars()
+                    .map(|ch| ch.to_digit(10).ok_or(Error::InvalidBattery(ch)))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if bank.len() < PART2_BATTERIES {
+                    return Err(Error::TooFewBatteries(line.to_string()));
+                }
+
+                Ok(bank)
+            })
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input) -> Result<Answer, Self::Error> {
+        let mut sum = 0usize;
+
+        for digits in banks {
+            let mut digits = &digits[..];
+            let mut number = 0usize;
+
+            for end in (0..PART1_BATTERIES).rev() {
+                let (index, value) =
+                    find_largest_number(digits.iter().cloned().take(digits.len() - end)).unwrap();
+
+                digits = &digits[(index + 1)..];
+                number += value as usize * 10usize.pow(end as u32);
+            }
+
+            sum += number;
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(banks: &Self::Input) -> Result<Option<Answer>, Self::Error> {
+        let mut sum = 0usize;
+
+        for digits in banks {
+            let mut digits = &digits[..];
+            let mut number = 0usize;
+
+            for end in (0..PART2_BATTERIES).rev() {
+                let (index, value) =
+                    find_largest_number(digits.iter().cloned().take(digits.len() - end)).unwrap();
+
+                digits = &digits[(index + 1)..];
+                number += value as usize * 10usize.pow(end as u32);
+            }
+
+            sum += number;
+        }
+
+        Ok(Some(sum.into()))
+    }
+}
+
+fn find_largest_number<T: Integer>(iterator: impl IntoIterator<Item = T>) -> Option<(usize, T)> {
+    let mut result = None;
+
+    for (index, current) in iterator.into_iter().enumerate() {
+        result = match result {
+            Some((_, value)) if current > value => Some((index, current)),
+            None => Some((index, current)),
+            _ => result,
+        };
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::aoc_test!(Day03);
+
+    #[test]
+    fn it_rejects_banks_with_too_few_batteries() {
+        assert_eq!(
+            Day03::parse("987654321111111\n811111111"),
+            Err(Error::TooFewBatteries("811111111".to_string()))
+        );
+    }
+}
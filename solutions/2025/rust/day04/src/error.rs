@@ -1,3 +1,4 @@
+use aoc_common::error::GridError;
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -7,3 +8,12 @@ pub enum Error {
     #[error("grid cannot be parsed from jagged input")]
     JaggedInput,
 }
+
+impl From<GridError<Error>> for Error {
+    fn from(error: GridError<Error>) -> Self {
+        match error {
+            GridError::JaggedInput => Self::JaggedInput,
+            GridError::InvalidCell(error) => error,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{error::Error, rolls::accessible_rolls, tile::Tile};
+use aoc_common::{Answer, Grid, Solution, trace};
+
+pub mod error;
+pub mod rolls;
+pub mod tile;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input = Grid<Tile>;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.parse::<Grid<Tile>>()?)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(accessible_rolls(grid).count().into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Option<Answer>, Self::Error> {
+        let mut grid = grid.clone();
+        let mut result = 0usize;
+
+        loop {
+            let _round = trace::span("round");
+            let positions_to_remove = accessible_rolls(&grid).collect::<Vec<_>>();
+
+            if positions_to_remove.is_empty() {
+                break;
+            }
+
+            for &position in &positions_to_remove {
+                grid[position] = Tile::Floor;
+            }
+
+            result += positions_to_remove.len();
+        }
+
+        Ok(Some(result.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::aoc_test!(Day04);
+}
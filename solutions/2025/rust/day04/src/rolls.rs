@@ -0,0 +1,21 @@
+use crate::tile::Tile;
+use aoc_common::{Grid, Point};
+
+pub fn accessible_rolls(grid: &Grid<Tile>) -> impl Iterator<Item = Point> {
+    grid.cells().filter_map(|(&tile, position)| {
+        if tile != Tile::Paper {
+            return None;
+        }
+
+        let paper_count = grid
+            .neighbor_positions(position)
+            .filter(|&neighbor_position| grid[neighbor_position] == Tile::Paper)
+            .count();
+
+        if paper_count < 4 {
+            Some(position)
+        } else {
+            None
+        }
+    })
+}
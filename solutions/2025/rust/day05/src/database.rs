@@ -1,55 +1,21 @@
 use crate::error::Error;
+use aoc_common::IntervalSet;
 use std::{ops::RangeInclusive, str::FromStr};
 
 #[derive(Debug, Clone)]
-pub struct Database(Vec<RangeInclusive<usize>>);
+pub struct Database(IntervalSet<usize>);
 
 impl Database {
     pub fn new(ranges: Vec<RangeInclusive<usize>>) -> Self {
-        let mut result = Self(ranges);
-        result.remove_overlaps();
-
-        result
+        Self(IntervalSet::new(ranges))
     }
 
     pub fn contains(&self, id: usize) -> bool {
-        for range in &self.0 {
-            if range.contains(&id) {
-                return true;
-            }
-        }
-
-        false
-    }
-
-    pub fn fresh_ingredient_count(&self) -> usize {
-        self.0
-            .iter()
-            .map(|range| range.end() + 1 - range.start())
-            .sum()
+        self.0.contains(id)
     }
 
-    fn remove_overlaps(&mut self) {
-        self.0.sort_by_key(|range| *range.start());
-        let mut modified_ranges = self.0.clone();
-
-        for (index, range) in self.0.iter().enumerate() {
-            for other_range in modified_ranges.iter_mut().skip(index + 1) {
-                if range.contains(other_range.start()) && range.contains(other_range.end()) {
-                    *other_range = 0..=0;
-                } else if range.contains(other_range.start()) {
-                    let start = range.end().clone() + 1;
-                    let end = other_range.end().clone().max(range.end().clone());
-                    *other_range = start..=end;
-                }
-            }
-        }
-
-        self.0 = modified_ranges
-            .iter()
-            .cloned()
-            .filter(|range| *range != (0..=0))
-            .collect();
+    pub fn fresh_ingredient_count(&self) -> u128 {
+        self.0.covered_len()
     }
 }
 
@@ -1,5 +1,6 @@
 use thiserror::Error;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
 pub enum Error {
     #[error("invalid database value specified, expected range but got '{0}'")]
@@ -0,0 +1,47 @@
+use crate::{database::Database, error::Error, ingredients::parse_ingredients};
+use aoc_common::{Answer, Solution};
+
+pub mod database;
+pub mod error;
+pub mod ingredients;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input = (Database, Vec<usize>);
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let (database_string, ingredients_string) = input
+            .split_once("\n\n")
+            .map(|(first, second)| (first.trim(), second.trim()))
+            .ok_or(Error::InvalidInput)?;
+
+        Ok((
+            database_string.parse::<Database>()?,
+            parse_ingredients(ingredients_string)?,
+        ))
+    }
+
+    fn part1((database, ingredients): &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(ingredients
+            .iter()
+            .filter(|&&ingredient| database.contains(ingredient))
+            .count()
+            .into())
+    }
+
+    fn part2((database, _): &Self::Input) -> Result<Option<Answer>, Self::Error> {
+        Ok(Some(database.fresh_ingredient_count().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::aoc_test!(Day05);
+}
@@ -0,0 +1,57 @@
+// I'll look at part 2 of this one later cos I genuinally cannot be fucking bothered with this
+// stupid puzzle right now.
+
+use crate::{error::Error, problem::Problem};
+use aoc_common::{Answer, Solution};
+
+pub mod error;
+pub mod operator;
+pub mod problem;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        let columns = read_input_columns(input);
+        let mut result = 0;
+
+        for column in columns {
+            result += Problem::try_from(&column[..])?.result();
+        }
+
+        Ok(result.into())
+    }
+}
+
+fn read_input_columns(input: &str) -> Vec<Vec<String>> {
+    let mut columns = Vec::new();
+
+    for line in input.lines() {
+        for (problem_index, value) in line.split_whitespace().enumerate() {
+            while columns.len() < problem_index + 1 {
+                columns.push(Vec::new());
+            }
+
+            columns[problem_index].push(value.to_string());
+        }
+    }
+
+    columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::aoc_test!(Day06);
+}